@@ -4,6 +4,7 @@ use rand::Rng;
 use std::fs::File;
 use std::cmp::Ordering;
 
+#[allow(clippy::collapsible_if)]
 pub fn conditionals() {
     // Example of if, else if, and else statements
     let score = 85;
@@ -4,6 +4,7 @@ use rand::Rng;
 use std::fs::File;
 use std::cmp::Ordering;
 
+#[allow(clippy::approx_constant)]
 pub fn datatypes() {
     const GREETING: &str = "Nice to meet you,"; 
     let name: &str = "Alice"; // Example slice variable
@@ -80,6 +81,6 @@ pub fn datatypes() {
     }
 
     // Random number
-    let random_number: u32 = rand::thread_rng().gen_range(1..101);
+    let random_number: u32 = rand::rng().random_range(1..101);
     println!("Random number generated: {}", random_number);
 }
@@ -55,3 +55,138 @@ pub fn custom_error_example() {
 }
 
 
+// Example of collecting every error instead of stopping at the first one
+// useful for form validation where the user wants to see all problems at once
+pub fn validate_all<T, E, F: Fn(&T) -> Result<(), E>>(items: &[T], check: F) -> Result<(), Vec<E>> {
+    let errors: Vec<E> = items
+        .iter()
+        .filter_map(|item| check(item).err())
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+
+// Example of collapsing a doubly nested Result / Option
+// the outer error comes first, otherwise whatever the inner value holds
+pub fn flatten_result<T, E>(r: Result<Result<T, E>, E>) -> Result<T, E> {
+    r.and_then(|inner| inner)
+}
+
+pub fn flatten_option<T>(o: Option<Option<T>>) -> Option<T> {
+    o.and_then(|inner| inner)
+}
+
+
+// Example of adding context to an error as it travels up
+// any error that can be displayed gets `msg` put in front of it
+pub trait WithContext<T> {
+    fn context(self, msg: &str) -> Result<T, String>;
+}
+
+impl<T, E: fmt::Display> WithContext<T> for Result<T, E> {
+    fn context(self, msg: &str) -> Result<T, String> {
+        self.map_err(|e| format!("{}: {}", msg, e))
+    }
+}
+
+
+// Example of retrying an operation that may fail temporarily
+// the operation runs up to `attempts` times (at least once) and the last error is kept
+pub fn retry<T, E, F: FnMut() -> Result<T, E>>(mut op: F, attempts: u32) -> Result<T, E> {
+    let mut result = op();
+    for _ in 1..attempts {
+        if result.is_ok() {
+            break;
+        }
+        result = op();
+    }
+    result
+}
+
+// Example of retrying a whole batch
+// each operation gets its own attempts, so one failure doesn't stop the others
+pub fn run_all_with_retry<T, E, F: FnMut() -> Result<T, E>>(ops: Vec<F>, attempts: u32) -> Vec<Result<T, E>> {
+    ops.into_iter().map(|op| retry(op, attempts)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_positive(n: &i32) -> Result<(), String> {
+        if *n > 0 {
+            Ok(())
+        } else {
+            Err(format!("{} is not positive", n))
+        }
+    }
+
+    #[test]
+    fn validate_all_collects_every_error() {
+        let result = validate_all(&[1, -2, 3, 0, 5], check_positive);
+        assert_eq!(
+            result,
+            Err(vec!["-2 is not positive".to_string(), "0 is not positive".to_string()])
+        );
+    }
+
+    #[test]
+    fn validate_all_passes_when_nothing_fails() {
+        assert_eq!(validate_all(&[1, 2, 3], check_positive), Ok(()));
+    }
+
+    #[test]
+    fn flatten_result_every_nesting() {
+        assert_eq!(flatten_result::<i32, &str>(Ok(Ok(1))), Ok(1));
+        assert_eq!(flatten_result::<i32, &str>(Ok(Err("inner"))), Err("inner"));
+        assert_eq!(flatten_result::<i32, &str>(Err("outer")), Err("outer"));
+    }
+
+    #[test]
+    fn flatten_option_every_nesting() {
+        assert_eq!(flatten_option(Some(Some(1))), Some(1));
+        assert_eq!(flatten_option::<i32>(Some(None)), None);
+        assert_eq!(flatten_option::<i32>(None), None);
+    }
+
+    #[test]
+    fn context_prefixes_io_error() {
+        let result: Result<String, io::Error> =
+            Err(io::Error::new(io::ErrorKind::NotFound, "no such file"));
+        assert_eq!(
+            result.context("reading config.txt"),
+            Err("reading config.txt: no such file".to_string())
+        );
+
+        let ok: Result<i32, io::Error> = Ok(3);
+        assert_eq!(ok.context("unused"), Ok(3));
+    }
+
+    #[test]
+    fn run_all_with_retry_mixed_operations() {
+        let mut failing_calls = 0;
+        let mut flaky_calls = 0;
+        let ops: Vec<Box<dyn FnMut() -> Result<u32, String>>> = vec![
+            Box::new(|| {
+                failing_calls += 1;
+                Err("always down".to_string())
+            }),
+            Box::new(|| {
+                flaky_calls += 1;
+                if flaky_calls < 3 { Err("not yet".to_string()) } else { Ok(flaky_calls) }
+            }),
+            Box::new(|| Ok(7)),
+        ];
+
+        let results = run_all_with_retry(ops, 3);
+
+        assert_eq!(results, vec![Err("always down".to_string()), Ok(3), Ok(7)]);
+        assert_eq!(failing_calls, 3);
+        assert_eq!(flaky_calls, 3);
+    }
+}
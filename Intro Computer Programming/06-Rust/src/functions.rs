@@ -1,4 +1,5 @@
 #![allow(unused)]
+#[allow(clippy::needless_return)]
 pub fn add_numbers(a: i32, b: i32) -> i32 {
     let sum = a + b;
     println!("The sum is: {}", sum);
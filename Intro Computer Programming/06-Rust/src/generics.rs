@@ -2,8 +2,9 @@
 // GENERIC TYPES EXAMPLES
 // ===========================
 
-use std::fmt::Display;
-use std::cmp::PartialOrd;
+use std::fmt::{self, Display};
+use std::cmp::{PartialOrd, Reverse};
+use std::collections::BinaryHeap;
 
 // 1. Basic Generic Function
 pub fn print_value<T: Display>(value: T) {
@@ -96,6 +97,25 @@ impl<T: Display> Point<T> {
     }
 }
 
+// Parsing "x,y" into a Point for any coordinate type that can be parsed
+impl<T: std::str::FromStr> std::str::FromStr for Point<T> {
+    type Err = String;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split(',').collect();
+        if parts.len() != 2 {
+            return Err(format!("expected 2 coordinates in '{}', found {}", s, parts.len()));
+        }
+        
+        let parse = |part: &str| {
+            part.trim()
+                .parse::<T>()
+                .map_err(|_| format!("invalid coordinate '{}' in '{}'", part.trim(), s))
+        };
+        Ok(Point::new(parse(parts[0])?, parse(parts[1])?))
+    }
+}
+
 // 8. Generic Container (Vector-like)
 #[derive(Debug)]
 pub struct Container<T> {
@@ -132,6 +152,29 @@ impl<T: Clone> Container<T> {
     }
 }
 
+impl<T: Ord> Container<T> {
+    // Keeps items sorted and unique by inserting at the binary-search position
+    pub fn insert_sorted(&mut self, item: T) {
+        if let Err(position) = self.items.binary_search(&item) {
+            self.items.insert(position, item);
+        }
+    }
+}
+
+// Prints like "[a, b, c]" using each item's Display instead of Debug
+impl<T: Display> Display for Container<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, item) in self.items.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", item)?;
+        }
+        write!(f, "]")
+    }
+}
+
 // 9. Generic function with where clause
 pub fn compare_and_display<T, U>(t: &T, u: &U) -> bool
 where
@@ -200,6 +243,9 @@ where
     K: Eq + std::hash::Hash,
 {
     data: std::collections::HashMap<K, &'a T>,
+    // Cell lets get() keep taking &self while still counting lookups
+    hits: std::cell::Cell<u64>,
+    misses: std::cell::Cell<u64>,
 }
 
 impl<'a, T, K> Cache<'a, T, K> 
@@ -210,11 +256,16 @@ where
     pub fn new() -> Self {
         Cache {
             data: std::collections::HashMap::new(),
+            hits: std::cell::Cell::new(0),
+            misses: std::cell::Cell::new(0),
         }
     }
     
     pub fn get(&self, key: &K) -> Option<&T> {
-        self.data.get(key).copied()
+        let value = self.data.get(key).copied();
+        let counter = if value.is_some() { &self.hits } else { &self.misses };
+        counter.set(counter.get() + 1);
+        value
     }
     
     pub fn insert(&mut self, key: K, value: &'a T) {
@@ -224,6 +275,23 @@ where
     pub fn contains_key(&self, key: &K) -> bool {
         self.data.contains_key(key)
     }
+    
+    pub fn hits(&self) -> u64 {
+        self.hits.get()
+    }
+    
+    pub fn misses(&self) -> u64 {
+        self.misses.get()
+    }
+    
+    pub fn hit_rate(&self) -> f64 {
+        let total = self.hits() + self.misses();
+        if total == 0 {
+            0.0
+        } else {
+            self.hits() as f64 / total as f64
+        }
+    }
 }
 
 // 14. Generic Option-like enum
@@ -260,10 +328,212 @@ impl<T> Maybe<T> {
     }
 }
 
+// 15. Generic slice head/tail helpers
+pub fn take_first<T: Clone>(items: &[T], n: usize) -> Vec<T> {
+    items[..n.min(items.len())].to_vec()
+}
+
+pub fn take_last<T: Clone>(items: &[T], n: usize) -> Vec<T> {
+    items[items.len().saturating_sub(n)..].to_vec()
+}
+
+// 16. Generic pairwise - consecutive overlapping pairs
+pub fn pairwise<T: Clone>(items: &[T]) -> Vec<(T, T)> {
+    items
+        .windows(2)
+        .map(|pair| (pair[0].clone(), pair[1].clone()))
+        .collect()
+}
+
+// 17. Generic indexing with Python-style negative indices
+pub fn wrap_index<T>(items: &[T], index: isize) -> Option<&T> {
+    let index = if index < 0 {
+        items.len().checked_sub(index.unsigned_abs())?
+    } else {
+        index as usize
+    };
+    items.get(index)
+}
+
+// 18. Generic filter_map over a slice - keep only the Some results
+pub fn filter_map_slice<T, U, F: Fn(&T) -> Option<U>>(items: &[T], f: F) -> Vec<U> {
+    let mut results = Vec::new();
+    for item in items {
+        if let Some(value) = f(item) {
+            results.push(value);
+        }
+    }
+    results
+}
+
+// 19. Generic min/max by a computed key
+pub fn min_by_key<T, K: Ord, F: Fn(&T) -> K>(items: &[T], key: F) -> Option<&T> {
+    items.iter().min_by_key(|item| key(item))
+}
+
+pub fn max_by_key<T, K: Ord, F: Fn(&T) -> K>(items: &[T], key: F) -> Option<&T> {
+    items.iter().max_by_key(|item| key(item))
+}
+
+// 20. Generic bounds-checked matrix access (works for ragged rows too)
+pub fn get_cell<T>(matrix: &[Vec<T>], row: usize, col: usize) -> Option<&T> {
+    matrix.get(row)?.get(col)
+}
+
+// 21. Generic map-reduce: transform every item, then fold the results together
+pub fn map_reduce<T, M, FM: Fn(&T) -> M, FR: Fn(M, M) -> M>(
+    items: &[T],
+    map: FM,
+    reduce: FR,
+    identity: M,
+) -> M
+where
+    M: Clone,
+{
+    items.iter().map(map).fold(identity, reduce)
+}
+
+// 22. Generic cycle detection by following a successor function
+pub fn has_cycle<T: Eq + std::hash::Hash + Clone, F: Fn(&T) -> Option<T>>(start: T, next: F) -> bool {
+    let mut visited = std::collections::HashSet::new();
+    let mut current = Some(start);
+    
+    while let Some(node) = current {
+        if !visited.insert(node.clone()) {
+            return true;
+        }
+        current = next(&node);
+    }
+    
+    false
+}
+
+// 23. Generic round-robin merge of any number of slices
+pub fn round_robin<T: Clone>(slices: &[&[T]]) -> Vec<T> {
+    let longest = slices.iter().map(|slice| slice.len()).max().unwrap_or(0);
+    let mut merged = Vec::new();
+    
+    for i in 0..longest {
+        for slice in slices {
+            if let Some(item) = slice.get(i) {
+                merged.push(item.clone());
+            }
+        }
+    }
+    
+    merged
+}
+
+// 24. Generic longest run of equal consecutive values (first run wins ties)
+pub fn longest_run<T: PartialEq + Clone>(items: &[T]) -> Option<(T, usize)> {
+    let mut best: Option<(T, usize)> = None;
+    let mut start = 0;
+    
+    for i in 1..=items.len() {
+        if i == items.len() || items[i] != items[start] {
+            let run_length = i - start;
+            if best.as_ref().is_none_or(|(_, length)| run_length > *length) {
+                best = Some((items[start].clone(), run_length));
+            }
+            start = i;
+        }
+    }
+    
+    best
+}
+
+// 25. Generic split on a predicate - matching elements act as separators and are dropped
+pub fn split_when<T: Clone, F: Fn(&T) -> bool>(items: &[T], pred: F) -> Vec<Vec<T>> {
+    items
+        .split(|item| pred(item))
+        .map(|group| group.to_vec())
+        .collect()
+}
+
+// 26. Bracket nesting depth using Stack<char> - the stack size is the current depth.
+// Other characters are ignored. On a mismatched or unexpected closing bracket the
+// scan stops and returns the deepest level reached so far; unclosed openers at the
+// end still count towards the depth they reached
+pub fn max_nesting_depth(s: &str) -> usize {
+    let mut stack: Stack<char> = Stack::new();
+    let mut deepest = 0;
+    
+    for c in s.chars() {
+        match c {
+            '(' | '[' | '{' => {
+                stack.push(c);
+                deepest = deepest.max(stack.size());
+            }
+            ')' | ']' | '}' => {
+                let expected = match c {
+                    ')' => '(',
+                    ']' => '[',
+                    _ => '{',
+                };
+                if stack.pop() != Some(expected) {
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+    
+    deepest
+}
+
+// 27. Generic k-way merge of sorted slices, keeping each value once.
+// A BinaryHeap is a max-heap, so Reverse turns it into a min-heap. It holds at
+// most one (value, slice, position) entry per slice: pop the smallest, then
+// push the next item from the same slice
+pub fn merge_k_sorted<T: Ord + Clone>(slices: &[&[T]]) -> Vec<T> {
+    let mut heap = BinaryHeap::new();
+    for (slice_index, slice) in slices.iter().enumerate() {
+        if let Some(first) = slice.first() {
+            heap.push(Reverse((first.clone(), slice_index, 0)));
+        }
+    }
+    
+    let mut merged: Vec<T> = Vec::new();
+    while let Some(Reverse((value, slice_index, position))) = heap.pop() {
+        if let Some(next) = slices[slice_index].get(position + 1) {
+            heap.push(Reverse((next.clone(), slice_index, position + 1)));
+        }
+        // Values come out in order, so a duplicate always matches the last one kept
+        if merged.last() != Some(&value) {
+            merged.push(value);
+        }
+    }
+    
+    merged
+}
+
+// 28. Generic split into n contiguous parts whose sizes differ by at most one.
+// The first `len % n` parts get one extra item. If n is larger than the slice,
+// the trailing parts are empty
+pub fn split_into_n<T: Clone>(items: &[T], n: usize) -> Vec<Vec<T>> {
+    if n == 0 {
+        return Vec::new();
+    }
+    
+    let base = items.len() / n;
+    let extra = items.len() % n;
+    let mut parts = Vec::with_capacity(n);
+    let mut start = 0;
+    
+    for i in 0..n {
+        let size = if i < extra { base + 1 } else { base };
+        parts.push(items[start..start + size].to_vec());
+        start += size;
+    }
+    
+    parts
+}
+
 // ===========================
 // MAIN FUNCTION WITH EXAMPLES
 // ===========================
 
+#[allow(clippy::approx_constant)]
 pub fn run_generics_examples() {
     println!("=== GENERICS EXAMPLES ===\n");
     
@@ -298,6 +568,14 @@ pub fn run_generics_examples() {
     int_point.print_coordinates();
     float_point.print_coordinates();
     
+    match "3,4".parse::<Point<i32>>() {
+        Ok(parsed) => println!("Parsed point: {:?}", parsed),
+        Err(e) => println!("Failed to parse point: {}", e),
+    }
+    if let Err(e) = "a,b".parse::<Point<i32>>() {
+        println!("Failed to parse point: {}", e);
+    }
+    
     // Pairs with different types
     let pair = Pair::new("key", 42);
     let bool_pair = Pair::new(true, 3.14);
@@ -315,12 +593,19 @@ pub fn run_generics_examples() {
     string_container.add("third");
     
     println!("String container: {:?}", string_container);
+    println!("String container (Display): {}", string_container);
     println!("Container length: {}", string_container.len());
     
     if let Some(item) = string_container.get(1) {
         println!("Item at index 1: {}", item);
     }
     
+    let mut sorted_container = Container::new();
+    for value in [5, 1, 3, 1, 4] {
+        sorted_container.insert_sorted(value);
+    }
+    println!("Sorted container: {:?}", sorted_container);
+    
     // Generic stack
     let mut stack = Stack::new();
     stack.push(1);
@@ -380,4 +665,240 @@ pub fn run_generics_examples() {
     }
     
     println!("Cache contains 'key2': {}", cache.contains_key(&"key2"));
-}
\ No newline at end of file
+    
+    cache.get(&"missing");
+    println!(
+        "Cache hits: {}, misses: {}, hit rate: {:.2}",
+        cache.hits(),
+        cache.misses(),
+        cache.hit_rate()
+    );
+
+    // Slice head/tail helpers
+    println!("\n--- Slice Helpers ---");
+    let values = vec![1, 2, 3, 4, 5];
+    println!("First 2 of {:?}: {:?}", values, take_first(&values, 2));
+    println!("Last 2 of {:?}: {:?}", values, take_last(&values, 2));
+    println!("Pairwise of {:?}: {:?}", values, pairwise(&values));
+    println!("Index -1 of {:?}: {:?}", values, wrap_index(&values, -1));
+    
+    let raw = ["1", "x", "3"];
+    let parsed: Vec<i32> = filter_map_slice(&raw, |s| s.parse().ok());
+    println!("Parsed {:?} into {:?}", raw, parsed);
+    
+    let words = ["pear", "banana", "fig"];
+    println!("Shortest word: {:?}", min_by_key(&words, |w| w.len()));
+    println!("Longest word: {:?}", max_by_key(&words, |w| w.len()));
+    
+    let matrix = vec![vec![1, 2, 3], vec![4]];
+    println!("Cell (0, 2): {:?}", get_cell(&matrix, 0, 2));
+    println!("Cell (1, 2): {:?}", get_cell(&matrix, 1, 2));
+    
+    let sum_of_squares: i32 = map_reduce(&[1, 2, 3], |x| x * x, |a, b| a + b, 0);
+    println!("Sum of squares of [1, 2, 3]: {}", sum_of_squares);
+    
+    let countdown_loops = has_cycle(5, |&n| if n > 0 { Some(n - 1) } else { None });
+    let clock_loops = has_cycle(0, |&hour| Some((hour + 1) % 12));
+    println!("Countdown has cycle: {}, clock has cycle: {}", countdown_loops, clock_loops);
+    
+    let merged = round_robin(&[&[1, 4][..], &[2, 5, 6], &[3]]);
+    println!("Round robin merge: {:?}", merged);
+    
+    let runs = [1, 1, 2, 2, 2, 3];
+    println!("Longest run in {:?}: {:?}", runs, longest_run(&runs));
+    
+    let separated = [1, 0, 2, 3, 0, 4];
+    println!("Split {:?} on zeros: {:?}", separated, split_when(&separated, |x| *x == 0));
+    
+    let nested = "[()]{}";
+    println!("Max nesting depth of '{}': {}", nested, max_nesting_depth(nested));
+    
+    let merged_sorted = merge_k_sorted(&[&[1, 4, 7][..], &[2, 4, 8], &[], &[3, 9]]);
+    println!("Merged sorted slices: {:?}", merged_sorted);
+    
+    let to_split = [1, 2, 3, 4, 5, 6, 7];
+    println!("{:?} split into 3: {:?}", to_split, split_into_n(&to_split, 3));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn take_first_and_last() {
+        let items = [1, 2, 3, 4];
+        assert_eq!(take_first(&items, 2), vec![1, 2]);
+        assert_eq!(take_last(&items, 2), vec![3, 4]);
+        assert_eq!(take_first(&items, 10), vec![1, 2, 3, 4]);
+        assert_eq!(take_last(&items, 10), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn pairwise_consecutive_pairs() {
+        assert_eq!(pairwise(&[1, 2, 3]), vec![(1, 2), (2, 3)]);
+        assert!(pairwise(&[1]).is_empty());
+    }
+
+    #[test]
+    fn wrap_index_positive_negative_and_out_of_range() {
+        let items = [10, 20, 30];
+        assert_eq!(wrap_index(&items, 0), Some(&10));
+        assert_eq!(wrap_index(&items, -1), Some(&30));
+        assert_eq!(wrap_index(&items, -3), Some(&10));
+        assert_eq!(wrap_index(&items, 3), None);
+        assert_eq!(wrap_index(&items, -4), None);
+    }
+
+    #[test]
+    fn insert_sorted_keeps_order_and_skips_duplicates() {
+        let mut container = Container::new();
+        for value in [5, 1, 4, 1, 3] {
+            container.insert_sorted(value);
+        }
+        assert_eq!(container.len(), 4);
+        let items: Vec<i32> = (0..container.len()).map(|i| *container.get(i).unwrap()).collect();
+        assert_eq!(items, vec![1, 3, 4, 5]);
+    }
+
+    #[test]
+    fn filter_map_slice_keeps_parsed_values() {
+        let parsed = filter_map_slice(&["1", "x", "3"], |s| s.parse::<i32>().ok());
+        assert_eq!(parsed, vec![1, 3]);
+    }
+
+    #[test]
+    fn min_and_max_by_string_length() {
+        let words = ["pear", "fig", "banana"];
+        assert_eq!(max_by_key(&words, |w| w.len()), Some(&"banana"));
+        assert_eq!(min_by_key(&words, |w| w.len()), Some(&"fig"));
+        assert_eq!(max_by_key(&[] as &[&str], |w| w.len()), None);
+    }
+
+    #[test]
+    fn get_cell_checks_row_and_column() {
+        let matrix = vec![vec![1, 2, 3], vec![4]];
+        assert_eq!(get_cell(&matrix, 0, 2), Some(&3));
+        assert_eq!(get_cell(&matrix, 2, 0), None);
+        assert_eq!(get_cell(&matrix, 1, 1), None);
+    }
+
+    #[test]
+    fn map_reduce_sum_of_squares() {
+        assert_eq!(map_reduce(&[1, 2, 3], |x| x * x, |a, b| a + b, 0), 14);
+    }
+
+    #[test]
+    fn has_cycle_terminating_chain() {
+        assert!(!has_cycle(5, |&n| if n > 0 { Some(n - 1) } else { None }));
+    }
+
+    #[test]
+    fn has_cycle_self_loop() {
+        assert!(has_cycle(7, |&n| Some(n)));
+    }
+
+    #[test]
+    fn point_from_str() {
+        let point: Point<i32> = "3,4".parse().unwrap();
+        assert_eq!((point.x, point.y), (3, 4));
+
+        assert_eq!(
+            "3".parse::<Point<i32>>().unwrap_err(),
+            "expected 2 coordinates in '3', found 1"
+        );
+        assert_eq!(
+            "a,b".parse::<Point<i32>>().unwrap_err(),
+            "invalid coordinate 'a' in 'a,b'"
+        );
+    }
+
+    #[test]
+    fn round_robin_uneven_slices() {
+        let merged = round_robin(&[&[1, 4][..], &[2, 5, 6], &[3]]);
+        assert_eq!(merged, vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn longest_run_finds_value_and_length() {
+        assert_eq!(longest_run(&[1, 1, 2, 2, 2, 3]), Some((2, 3)));
+        assert_eq!(longest_run::<i32>(&[]), None);
+    }
+
+    #[test]
+    fn split_when_drops_separators() {
+        assert_eq!(split_when(&[1, 0, 2, 3, 0, 4], |x| *x == 0), vec![vec![1], vec![2, 3], vec![4]]);
+    }
+
+    #[test]
+    fn cache_counts_hits_and_misses() {
+        let apple = String::from("apple");
+        let mut cache: Cache<String, u32> = Cache::new();
+        assert_eq!(cache.hit_rate(), 0.0);
+
+        cache.insert(1, &apple);
+        assert_eq!(cache.get(&1), Some(&apple));
+        assert_eq!(cache.get(&1), Some(&apple));
+        assert_eq!(cache.get(&1), Some(&apple));
+        assert_eq!(cache.get(&2), None);
+
+        assert_eq!(cache.hits(), 3);
+        assert_eq!(cache.misses(), 1);
+        assert_eq!(cache.hit_rate(), 0.75);
+    }
+
+    #[test]
+    fn max_nesting_depth_balanced() {
+        assert_eq!(max_nesting_depth("((()))"), 3);
+        assert_eq!(max_nesting_depth("[()]{}"), 2);
+        assert_eq!(max_nesting_depth(""), 0);
+    }
+
+    #[test]
+    fn max_nesting_depth_stops_at_mismatch() {
+        // Depth 2 is reached before ']' fails to close '(' - the rest is ignored
+        assert_eq!(max_nesting_depth("[(](((("), 2);
+        assert_eq!(max_nesting_depth(")((("), 0);
+    }
+
+    #[test]
+    fn container_display() {
+        let mut container = Container::new();
+        assert_eq!(container.to_string(), "[]");
+        container.add("a");
+        container.add("b");
+        container.add("c");
+        assert_eq!(format!("{}", container), "[a, b, c]");
+    }
+
+    #[test]
+    fn merge_k_sorted_three_slices() {
+        let merged = merge_k_sorted(&[&[1, 4, 7][..], &[2, 5, 8], &[3, 6, 9]]);
+        assert_eq!(merged, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn merge_k_sorted_skips_empty_slices() {
+        assert_eq!(merge_k_sorted(&[&[][..], &[1, 3], &[], &[2]]), vec![1, 2, 3]);
+        assert!(merge_k_sorted::<i32>(&[]).is_empty());
+        assert!(merge_k_sorted::<i32>(&[&[], &[]]).is_empty());
+    }
+
+    #[test]
+    fn merge_k_sorted_drops_duplicates() {
+        let merged = merge_k_sorted(&[&[1, 2, 2][..], &[2, 3], &[1, 3]]);
+        assert_eq!(merged, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn split_into_n_gives_extra_items_to_early_parts() {
+        let parts = split_into_n(&[1, 2, 3, 4, 5, 6, 7], 3);
+        let sizes: Vec<usize> = parts.iter().map(|part| part.len()).collect();
+        assert_eq!(sizes, vec![3, 2, 2]);
+        assert_eq!(parts, vec![vec![1, 2, 3], vec![4, 5], vec![6, 7]]);
+    }
+
+    #[test]
+    fn split_into_n_zero_parts_is_empty() {
+        assert!(split_into_n(&[1, 2, 3], 0).is_empty());
+    }
+}
@@ -1,4 +1,5 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
 
 pub fn hashmaps() {
     // Create a new HashMap
@@ -30,4 +31,320 @@ pub fn hashmaps() {
     } else {
         println!("Bob's score has been removed.");
     }
-}
\ No newline at end of file
+    // Counting duplicates with the entry API
+    let readings = [1, 1, 2, 3, 3, 3];
+    println!("Duplicates in {:?}: {:?}", readings, duplicate_report(&readings));
+    println!("Distinct values in {:?}: {}", readings, count_distinct(&readings));
+
+    // Set operations backed by HashSet
+    let a = [1, 2, 3];
+    let b = [2, 3, 4];
+    println!("Union: {:?}", union(&a, &b));
+    println!("Intersection: {:?}", intersection(&a, &b));
+    println!("Difference: {:?}", difference(&a, &b));
+
+    // Two-sum: remember each value's index so the partner can be looked up in O(1)
+    let nums = [2, 7, 11, 15];
+    println!("Two-sum {:?} to 9: {:?}", nums, two_sum(&nums, 9));
+
+    // Sliding window: remember where each char was last seen
+    let text = "abcabcbb";
+    println!("Longest unique substring in '{}': {}", text, longest_unique_substring(text));
+
+    // Rarest characters first - the order a Huffman tree is built in
+    println!("Frequency order of 'banana': {:?}", frequency_order("banana"));
+
+    // Pairing two slices into a map
+    let zipped = zip_to_map(&["a", "b", "c"], &[1, 2]);
+    println!("Zipped map: {:?}", zipped);
+
+    // Comparing slices while ignoring order
+    println!("[1, 2, 2, 3] and [3, 2, 1, 2] hold the same items: {}", same_multiset(&[1, 2, 2, 3], &[3, 2, 1, 2]));
+
+    // Character histogram, most common first
+    println!("Character report for 'hello world':\n{}", char_frequency_report("hello world"));
+
+    // Counting distinct items as they stream in
+    let mut visitors = DistinctCounter::new();
+    for name in ["ann", "bob", "ann", "cy", "bob"] {
+        visitors.add(name);
+        println!("After {}: {} distinct visitors", name, visitors.count());
+    }
+}
+
+// Counts every item, then keeps only the ones seen more than once
+pub fn duplicate_report<T: Eq + Hash + Clone>(items: &[T]) -> HashMap<T, usize> {
+    let mut counts: HashMap<T, usize> = HashMap::new();
+    for item in items {
+        *counts.entry(item.clone()).or_insert(0) += 1;
+    }
+
+    counts.retain(|_, count| *count > 1);
+    counts
+}
+
+// A HashSet only keeps one copy of each item, so its size is the distinct count
+pub fn count_distinct<T: Eq + Hash>(items: &[T]) -> usize {
+    items.iter().collect::<HashSet<&T>>().len()
+}
+
+// Set operations that keep the order items were first seen in.
+// `seen` stops an item from being added twice
+pub fn union<T: Eq + Hash + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    let mut seen = HashSet::new();
+    a.iter()
+        .chain(b)
+        .filter(|item| seen.insert(*item))
+        .cloned()
+        .collect()
+}
+
+pub fn intersection<T: Eq + Hash + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    let in_b: HashSet<&T> = b.iter().collect();
+    let mut seen = HashSet::new();
+    a.iter()
+        .filter(|item| in_b.contains(item) && seen.insert(*item))
+        .cloned()
+        .collect()
+}
+
+pub fn difference<T: Eq + Hash + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    let in_b: HashSet<&T> = b.iter().collect();
+    let mut seen = HashSet::new();
+    a.iter()
+        .filter(|item| !in_b.contains(item) && seen.insert(*item))
+        .cloned()
+        .collect()
+}
+
+// For each number, check whether the value it needs has already been seen.
+// Only earlier indices are stored, so an element can never pair with itself
+pub fn two_sum(nums: &[i32], target: i32) -> Option<(usize, usize)> {
+    let mut seen: HashMap<i32, usize> = HashMap::new();
+    for (i, &num) in nums.iter().enumerate() {
+        // checked_sub: a needed value outside i32 can't be in the slice anyway
+        if let Some(&j) = target.checked_sub(num).and_then(|need| seen.get(&need)) {
+            return Some((j, i));
+        }
+        seen.insert(num, i);
+    }
+    None
+}
+
+// Slide a window over the string, remembering the char position each character
+// was last seen at. A repeat inside the window moves the window's start past it
+pub fn longest_unique_substring(s: &str) -> usize {
+    let mut last_seen: HashMap<char, usize> = HashMap::new();
+    let mut start = 0;
+    let mut longest = 0;
+
+    // Positions are counted in chars, not bytes, so multibyte text works too
+    for (pos, (_, c)) in s.char_indices().enumerate() {
+        if let Some(&prev) = last_seen.get(&c) {
+            start = start.max(prev + 1);
+        }
+        last_seen.insert(c, pos);
+        longest = longest.max(pos + 1 - start);
+    }
+    longest
+}
+
+// Counts each character, then sorts rarest first. Ties are broken by the
+// character itself so the result doesn't depend on HashMap iteration order
+pub fn frequency_order(text: &str) -> Vec<(char, usize)> {
+    let mut counts: HashMap<char, usize> = HashMap::new();
+    for c in text.chars() {
+        *counts.entry(c).or_insert(0) += 1;
+    }
+
+    let mut order: Vec<(char, usize)> = counts.into_iter().collect();
+    order.sort_by_key(|&(c, count)| (count, c));
+    order
+}
+
+// Pairs keys with values up to the shorter slice. `insert` replaces the old
+// value, so a later duplicate key wins
+pub fn zip_to_map<K: Eq + Hash + Clone, V: Clone>(keys: &[K], values: &[V]) -> HashMap<K, V> {
+    let mut map = HashMap::new();
+    for (key, value) in keys.iter().zip(values) {
+        map.insert(key.clone(), value.clone());
+    }
+    map
+}
+
+// Same items with the same counts, in any order. Counting up for `a` and down
+// for `b` means every count must end at zero
+pub fn same_multiset<T: Eq + Hash + Clone>(a: &[T], b: &[T]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut counts: HashMap<T, i64> = HashMap::new();
+    for item in a {
+        *counts.entry(item.clone()).or_insert(0) += 1;
+    }
+    for item in b {
+        *counts.entry(item.clone()).or_insert(0) -= 1;
+    }
+
+    counts.values().all(|&count| count == 0)
+}
+
+// One "<char>: <count>" line per character, most common first and ties in
+// character order. Whitespace is skipped
+pub fn char_frequency_report(s: &str) -> String {
+    let mut counts: HashMap<char, usize> = HashMap::new();
+    for c in s.chars().filter(|c| !c.is_whitespace()) {
+        *counts.entry(c).or_insert(0) += 1;
+    }
+
+    let mut entries: Vec<(char, usize)> = counts.into_iter().collect();
+    entries.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+    entries
+        .iter()
+        .map(|(c, count)| format!("{}: {}", c, count))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+// Keeps a running count of distinct items. The HashSet remembers what has
+// been seen, and `insert` ignores anything already in it
+#[derive(Debug)]
+pub struct DistinctCounter<T: Eq + Hash> {
+    seen: HashSet<T>,
+}
+
+impl<T: Eq + Hash> DistinctCounter<T> {
+    pub fn new() -> Self {
+        DistinctCounter { seen: HashSet::new() }
+    }
+
+    pub fn add(&mut self, item: T) {
+        self.seen.insert(item);
+    }
+
+    pub fn count(&self) -> usize {
+        self.seen.len()
+    }
+}
+
+impl<T: Eq + Hash> Default for DistinctCounter<T> {
+    fn default() -> Self {
+        DistinctCounter::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duplicate_report_keeps_only_repeats() {
+        let report = duplicate_report(&[1, 1, 2, 3, 3, 3]);
+        assert_eq!(report, HashMap::from([(1, 2), (3, 3)]));
+    }
+
+    #[test]
+    fn count_distinct_with_and_without_duplicates() {
+        assert_eq!(count_distinct(&[1, 1, 2, 3, 3, 3]), 3);
+        assert_eq!(count_distinct(&["a", "b", "c"]), 3);
+        assert_eq!(count_distinct::<i32>(&[]), 0);
+    }
+
+    #[test]
+    fn set_operations_keep_first_seen_order() {
+        let a = [1, 2, 3];
+        let b = [2, 3, 4];
+        assert_eq!(union(&a, &b), vec![1, 2, 3, 4]);
+        assert_eq!(intersection(&a, &b), vec![2, 3]);
+        assert_eq!(difference(&a, &b), vec![1]);
+    }
+
+    #[test]
+    fn set_operations_deduplicate() {
+        assert_eq!(union(&[1, 1], &[1]), vec![1]);
+        assert_eq!(intersection(&[2, 2], &[2]), vec![2]);
+        assert_eq!(difference(&[1, 1], &[]), vec![1]);
+    }
+
+    #[test]
+    fn two_sum_finds_pair() {
+        assert_eq!(two_sum(&[2, 7, 11, 15], 9), Some((0, 1)));
+        assert_eq!(two_sum(&[3, 3], 6), Some((0, 1)));
+    }
+
+    #[test]
+    fn two_sum_no_pair() {
+        assert_eq!(two_sum(&[1, 2, 4], 10), None);
+        assert_eq!(two_sum(&[i32::MIN, 1], i32::MAX), None);
+    }
+
+    #[test]
+    fn two_sum_does_not_reuse_an_index() {
+        // 3 + 3 would be 6, but there is only one 3
+        assert_eq!(two_sum(&[3, 4], 6), None);
+    }
+
+    #[test]
+    fn longest_unique_substring_examples() {
+        assert_eq!(longest_unique_substring("abcabcbb"), 3);
+        assert_eq!(longest_unique_substring("bbbbb"), 1);
+        assert_eq!(longest_unique_substring(""), 0);
+        assert_eq!(longest_unique_substring("abba"), 2);
+    }
+
+    #[test]
+    fn frequency_order_distinct_counts() {
+        assert_eq!(frequency_order("banana"), vec![('b', 1), ('n', 2), ('a', 3)]);
+    }
+
+    #[test]
+    fn frequency_order_ties_sorted_by_char() {
+        assert_eq!(frequency_order("cabba"), vec![('c', 1), ('a', 2), ('b', 2)]);
+        assert!(frequency_order("").is_empty());
+    }
+
+    #[test]
+    fn zip_to_map_pairs_keys_and_values() {
+        let map = zip_to_map(&["a", "b"], &[1, 2]);
+        assert_eq!(map, HashMap::from([("a", 1), ("b", 2)]));
+    }
+
+    #[test]
+    fn zip_to_map_stops_at_shorter_slice() {
+        assert_eq!(zip_to_map(&["a", "b", "c"], &[1]), HashMap::from([("a", 1)]));
+        assert_eq!(zip_to_map(&["a"], &[1, 2, 3]), HashMap::from([("a", 1)]));
+        assert_eq!(zip_to_map(&["a", "a"], &[1, 2]), HashMap::from([("a", 2)]));
+    }
+
+    #[test]
+    fn same_multiset_ignores_order() {
+        assert!(same_multiset(&[1, 2, 2, 3], &[3, 2, 1, 2]));
+    }
+
+    #[test]
+    fn same_multiset_checks_multiplicity() {
+        assert!(!same_multiset(&[1, 2, 2, 3], &[1, 2, 3, 3]));
+    }
+
+    #[test]
+    fn char_frequency_report_exact_output() {
+        assert_eq!(
+            char_frequency_report("hello world"),
+            "l: 3\no: 2\nd: 1\ne: 1\nh: 1\nr: 1\nw: 1"
+        );
+        assert_eq!(char_frequency_report("   "), "");
+    }
+
+    #[test]
+    fn distinct_counter_ignores_repeats() {
+        let mut counter = DistinctCounter::new();
+        let mut counts = Vec::new();
+        for item in [1, 2, 1, 3, 2, 3, 4] {
+            counter.add(item);
+            counts.push(counter.count());
+        }
+        assert_eq!(counts, vec![1, 2, 2, 3, 3, 3, 4]);
+    }
+}
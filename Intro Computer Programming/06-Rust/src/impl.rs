@@ -2,7 +2,8 @@
 // IMPLEMENTATION EXAMPLES
 // ===========================
 
-use std::fmt::Display;
+use std::collections::HashMap;
+use std::fmt::{self, Display};
 
 // 1. Basic Struct with Implementation
 #[derive(Debug, Clone)]
@@ -156,6 +157,22 @@ impl Temperature {
     pub fn is_freezing(&self) -> bool {
         self.to_celsius() <= 0.0
     }
+    
+    pub fn format_precision(&self, decimals: usize) -> String {
+        let (value, unit) = match self {
+            Temperature::Celsius(c) => (c, "°C"),
+            Temperature::Fahrenheit(f) => (f, "°F"),
+            Temperature::Kelvin(k) => (k, "K"),
+        };
+        format!("{:.*}{}", decimals, value, unit)
+    }
+}
+
+// Display uses one decimal place unless a precision is given, e.g. {:.2}
+impl Display for Temperature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.format_precision(f.precision().unwrap_or(1)))
+    }
 }
 
 // 8. Implementation with constants
@@ -167,6 +184,22 @@ impl Rectangle {
     }
 }
 
+// 9. Grouping structs into a HashMap of buckets
+pub fn group_by_age_bucket(people: &[Person]) -> HashMap<&'static str, Vec<&Person>> {
+    let mut buckets: HashMap<&'static str, Vec<&Person>> = HashMap::new();
+    
+    for person in people {
+        let bucket = match person.age {
+            0..=17 => "minor",
+            18..=64 => "adult",
+            _ => "senior",
+        };
+        buckets.entry(bucket).or_default().push(person);
+    }
+    
+    buckets
+}
+
 // Main function to demonstrate implementations
 pub fn run_impl_examples() {
     println!("=== IMPLEMENTATION EXAMPLES ===\n");
@@ -218,7 +251,66 @@ pub fn run_impl_examples() {
     println!("77°F in Celsius: {:.1}°C", temp_f.to_celsius());
     println!("298.15K in Celsius: {:.1}°C", temp_k.to_celsius());
     println!("Is 25°C freezing? {}", temp_c.is_freezing());
+    println!("Displayed: {}, {}, {}", temp_c, temp_f, temp_k);
+    println!("Kelvin with 3 decimals: {}", temp_k.format_precision(3));
     
     let freezing = Temperature::Celsius(-5.0);
     println!("Is -5°C freezing? {}", freezing.is_freezing());
-}
\ No newline at end of file
+    
+    // Grouping people by age bucket
+    let people = vec![
+        Person::new(String::from("Tom"), 12, String::from("tom@example.com")),
+        Person::new(String::from("Sara"), 34, String::from("sara@example.com")),
+        Person::new(String::from("Ruth"), 71, String::from("ruth@example.com")),
+    ];
+    let buckets = group_by_age_bucket(&people);
+    for bucket in ["minor", "adult", "senior"] {
+        if let Some(members) = buckets.get(bucket) {
+            let names: Vec<&str> = members.iter().map(|p| p.name.as_str()).collect();
+            println!("{}: {:?}", bucket, names);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn person(name: &str, age: u32) -> Person {
+        Person::new(name.to_string(), age, format!("{}@example.com", name))
+    }
+
+    fn names(people: &[&Person]) -> Vec<String> {
+        people.iter().map(|p| p.name.clone()).collect()
+    }
+
+    #[test]
+    fn group_by_age_bucket_boundaries() {
+        let people = [
+            person("kid", 17),
+            person("new_adult", 18),
+            person("pre_senior", 64),
+            person("senior", 65),
+        ];
+        let buckets = group_by_age_bucket(&people);
+
+        assert_eq!(buckets.len(), 3);
+        assert_eq!(names(&buckets["minor"]), vec!["kid"]);
+        assert_eq!(names(&buckets["adult"]), vec!["new_adult", "pre_senior"]);
+        assert_eq!(names(&buckets["senior"]), vec!["senior"]);
+    }
+
+    #[test]
+    fn temperature_format_precision() {
+        assert_eq!(Temperature::Celsius(21.456).format_precision(0), "21°C");
+        assert_eq!(Temperature::Fahrenheit(70.5).format_precision(2), "70.50°F");
+        assert_eq!(Temperature::Kelvin(273.15).format_precision(3), "273.150K");
+    }
+
+    #[test]
+    fn temperature_display_uses_formatter_precision() {
+        assert_eq!(Temperature::Celsius(21.456).to_string(), "21.5°C");
+        assert_eq!(format!("{:.2}", Temperature::Fahrenheit(70.5)), "70.50°F");
+        assert_eq!(format!("{:.0}", Temperature::Kelvin(273.15)), "273K");
+    }
+}
@@ -2,7 +2,8 @@
 // LIFETIMES EXAMPLES
 // ===========================
 
-use std::fmt::Display;
+use std::borrow::Cow;
+use std::fmt::{self, Display};
 
 // 1. Basic Lifetime Annotation
 pub fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
@@ -14,19 +15,20 @@ pub fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
 }
 
 // 2. Lifetime with Structs
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub struct Book<'a> {
     pub title: &'a str,
     pub author: &'a str,
+    pub year: u32,
 }
 
 impl<'a> Book<'a> {
-    pub fn new(title: &'a str, author: &'a str) -> Book<'a> {
-        Book { title, author }
+    pub fn new(title: &'a str, author: &'a str, year: u32) -> Book<'a> {
+        Book { title, author, year }
     }
     
     pub fn get_info(&self) -> String {
-        format!("{} by {}", self.title, self.author)
+        self.to_string()
     }
     
     pub fn get_title(&self) -> &'a str {
@@ -36,9 +38,21 @@ impl<'a> Book<'a> {
     pub fn get_author(&self) -> &'a str {
         self.author
     }
+    
+    // The other book may borrow from different data, hence its own lifetime
+    pub fn is_older_than(&self, other: &Book) -> bool {
+        self.year < other.year
+    }
+}
+
+impl Display for Book<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} by {}", self.title, self.author)
+    }
 }
 
 // 3. Multiple Lifetime Parameters
+#[allow(clippy::if_same_then_else)]
 pub fn compare_and_return<'a, 'b>(x: &'a str, y: &'b str, return_first: bool) -> &'a str
 where
     'b: 'a, // 'b outlives 'a
@@ -52,10 +66,10 @@ where
 
 // 4. Lifetime Elision Examples
 pub fn first_word(s: &str) -> &str {
-    let bytes = s.as_bytes();
-    
-    for (i, &item) in bytes.iter().enumerate() {
-        if item == b' ' {
+    // char_indices gives byte offsets, so tabs, newlines and other
+    // Unicode whitespace all end the word on a valid char boundary
+    for (i, c) in s.char_indices() {
+        if c.is_whitespace() {
             return &s[..i];
         }
     }
@@ -68,6 +82,7 @@ pub fn get_first_element(list: &[i32]) -> &i32 {
 }
 
 // 5. Static Lifetime
+#[allow(clippy::redundant_static_lifetimes)]
 static GLOBAL_STR: &'static str = "This lives for the entire program";
 
 pub fn get_static_str() -> &'static str {
@@ -96,11 +111,17 @@ impl<'a, T: Display> Wrapper<'a, T> {
     pub fn get_value(&self) -> &'a T {
         self.value
     }
+    
+    // The closure only borrows the value, so nothing is cloned or moved
+    pub fn map<U, F: Fn(&T) -> U>(&self, f: F) -> U {
+        f(self.value)
+    }
 }
 
 // 7. Trait with Lifetime Parameters
 pub trait Summary {
     fn summarize(&self) -> String;
+    #[allow(clippy::needless_lifetimes)]
     fn get_snippet<'a>(&'a self) -> &'a str;
 }
 
@@ -112,23 +133,17 @@ pub struct Article<'a> {
 
 impl<'a> Summary for Article<'a> {
     fn summarize(&self) -> String {
-        let snippet = if self.content.len() > 50 {
-            &self.content[..50]
-        } else {
-            self.content
-        };
+        let snippet = truncate_str(self.content, 50);
         format!("{}: {}", self.headline, snippet)
     }
     
+    #[allow(clippy::needless_lifetimes)]
     fn get_snippet<'b>(&'b self) -> &'b str {
-        if self.content.len() > 100 {
-            &self.content[..100]
-        } else {
-            self.content
-        }
+        truncate_str(self.content, 100)
     }
 }
 
+#[allow(clippy::needless_lifetimes)]
 pub fn get_summary<'a>(item: &'a dyn Summary) -> String {
     item.summarize()
 }
@@ -174,19 +189,64 @@ impl<'a, 'b> RefHolder<'a, 'b> {
     pub fn get_sum(&self) -> i32 {
         self.first + self.second
     }
+    
+    // Returning a copy sidesteps choosing between 'a and 'b for a returned reference
+    pub fn max(&self) -> i32 {
+        *self.first.max(self.second)
+    }
+    
+    pub fn min(&self) -> i32 {
+        *self.first.min(self.second)
+    }
 }
 
 // 11. Iterator with lifetimes
 pub struct StrSplit<'a> {
     remainder: Option<&'a str>,
-    delimiter: char,
+    // Borrowed for string delimiters; a char has to be turned into an owned string
+    delimiter: Cow<'a, str>,
+    // How many parts may still be produced; None means no limit
+    parts_left: Option<usize>,
 }
 
 impl<'a> StrSplit<'a> {
-    pub fn new(string: &'a str, delimiter: char) -> Self {
+    pub fn new(string: &'a str, delimiter: &'a str) -> Self {
         StrSplit {
             remainder: Some(string),
-            delimiter,
+            delimiter: Cow::Borrowed(delimiter),
+            parts_left: None,
+        }
+    }
+    
+    // Like str::splitn: at most `max_parts` parts, the last one holds the rest
+    pub fn with_limit(string: &'a str, delimiter: &'a str, max_parts: usize) -> Self {
+        StrSplit {
+            remainder: if max_parts == 0 { None } else { Some(string) },
+            delimiter: Cow::Borrowed(delimiter),
+            parts_left: Some(max_parts),
+        }
+    }
+    
+    pub fn from_char(string: &'a str, delimiter: char) -> Self {
+        StrSplit {
+            remainder: Some(string),
+            delimiter: Cow::Owned(delimiter.to_string()),
+            parts_left: None,
+        }
+    }
+    
+    // An empty delimiter would match everywhere, so treat it as no delimiter
+    fn delimiter(&self) -> Option<&str> {
+        if self.delimiter.is_empty() {
+            None
+        } else {
+            Some(&self.delimiter)
+        }
+    }
+    
+    fn used_one_part(&mut self) {
+        if let Some(left) = self.parts_left.as_mut() {
+            *left -= 1;
         }
     }
 }
@@ -195,17 +255,48 @@ impl<'a> Iterator for StrSplit<'a> {
     type Item = &'a str;
     
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(remainder) = self.remainder {
-            if let Some(index) = remainder.find(self.delimiter) {
-                let (before, after) = remainder.split_at(index);
-                self.remainder = Some(&after[1..]);
-                Some(before)
-            } else {
-                self.remainder = None;
-                Some(remainder)
-            }
-        } else {
+        let remainder = self.remainder?;
+        // The last part allowed by a limit takes the whole remainder
+        let found = if self.parts_left == Some(1) {
             None
+        } else {
+            self.delimiter().and_then(|d| remainder.find(d))
+        };
+        
+        if let Some(index) = found {
+            let (before, after) = remainder.split_at(index);
+            // Skip the whole delimiter: its byte length, not one byte, so
+            // multibyte delimiters like '※' never slice inside a char
+            self.remainder = Some(&after[self.delimiter.len()..]);
+            self.used_one_part();
+            Some(before)
+        } else {
+            self.remainder = None;
+            Some(remainder)
+        }
+    }
+}
+
+// Taking parts from the back: the remainder is always the unsplit middle,
+// so next() and next_back() meet without skipping or repeating a part
+impl<'a> DoubleEndedIterator for StrSplit<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let remainder = self.remainder?;
+        let found = self.delimiter().and_then(|d| match self.parts_left {
+            // With a limit only the first `left - 1` delimiters split, so the
+            // last part starts after the last of those, not after the last one
+            Some(left) => remainder.match_indices(d).take(left - 1).last().map(|(i, _)| i),
+            None => remainder.rfind(d),
+        });
+        
+        if let Some(index) = found {
+            let (before, after) = remainder.split_at(index);
+            self.remainder = Some(before);
+            self.used_one_part();
+            Some(&after[self.delimiter.len()..])
+        } else {
+            self.remainder = None;
+            Some(remainder)
         }
     }
 }
@@ -237,6 +328,20 @@ impl<'a, T> Container<'a, T> {
     pub fn get(&self, index: usize) -> Option<&T> {
         self.items.get(index)
     }
+    
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+}
+
+// Lets `for item in &container` work by handing out the slice's own iterator
+impl<'b, 'a, T> IntoIterator for &'b Container<'a, T> {
+    type Item = &'b T;
+    type IntoIter = std::slice::Iter<'b, T>;
+    
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
 }
 
 impl<'a, T: Display> Container<'a, T> {
@@ -276,38 +381,169 @@ impl<'a> Parser<'a> {
     }
     
     pub fn parse_word(&mut self) -> Option<&'a str> {
-        let start = self.position;
-        
-        while self.position < self.input.len() {
-            if self.input.chars().nth(self.position).unwrap().is_whitespace() {
-                break;
-            }
-            self.position += 1;
+        let word = self.peek_word()?;
+        self.position += word.len();
+        self.skip_whitespace();
+        Some(word)
+    }
+    
+    // Consumes the next word only if it is a valid integer
+    pub fn parse_integer(&mut self) -> Result<i64, String> {
+        let word = self.peek_word().ok_or("unexpected end of input")?;
+        let number = word
+            .parse::<i64>()
+            .map_err(|_| format!("expected integer, found '{}'", word))?;
+        self.parse_word();
+        Ok(number)
+    }
+    
+    // Collects every remaining word; leading whitespace is skipped first
+    // because parse_word stops as soon as it sees whitespace
+    pub fn tokenize(mut self) -> Vec<&'a str> {
+        self.skip_whitespace();
+        let mut words = Vec::new();
+        while let Some(word) = self.parse_word() {
+            words.push(word);
         }
+        words
+    }
+    
+    // Looks at the next word without moving position
+    pub fn peek_word(&self) -> Option<&'a str> {
+        // position is a byte offset that always sits on a char boundary,
+        // so slicing from it is O(1) and each char is scanned only once
+        let rest = &self.input[self.position..];
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
         
-        if start < self.position {
-            let word = &self.input[start..self.position];
-            self.skip_whitespace();
-            Some(word)
+        if end > 0 {
+            Some(&rest[..end])
         } else {
             None
         }
     }
     
     fn skip_whitespace(&mut self) {
-        while self.position < self.input.len() {
-            if !self.input.chars().nth(self.position).unwrap().is_whitespace() {
-                break;
+        let rest = &self.input[self.position..];
+        self.position += rest.len() - rest.trim_start().len();
+    }
+}
+
+// 17. Extracting a column from CSV text using StrSplit
+pub fn extract_column(csv: &str, index: usize) -> Vec<String> {
+    csv.lines()
+        .filter_map(|line| StrSplit::new(line, ",").nth(index))
+        .map(|value| value.to_string())
+        .collect()
+}
+
+// 18. Owning split iterator - no lifetime needed because it owns its data
+pub struct OwnedSplit {
+    remainder: Option<String>,
+    delimiter: char,
+}
+
+impl OwnedSplit {
+    pub fn new(string: String, delimiter: char) -> Self {
+        OwnedSplit {
+            remainder: Some(string),
+            delimiter,
+        }
+    }
+}
+
+impl Iterator for OwnedSplit {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut remainder = self.remainder.take()?;
+        if let Some(index) = remainder.find(self.delimiter) {
+            let after = remainder.split_off(index + self.delimiter.len_utf8());
+            remainder.truncate(index);
+            self.remainder = Some(after);
+        }
+        Some(remainder)
+    }
+}
+
+// 19. Tiny lexer - tokens borrow from the input string
+#[derive(Debug, PartialEq)]
+pub enum Token<'a> {
+    Word(&'a str),
+    Number(i64),
+    Punct(char),
+}
+
+pub fn tokenize<'a>(input: &'a str) -> Vec<Token<'a>> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        if c.is_whitespace() {
+            continue;
+        }
+
+        if c.is_alphanumeric() || c == '_' {
+            let is_number = c.is_ascii_digit();
+            let mut end = start + c.len_utf8();
+
+            // Consume the rest of the run of the same kind
+            while let Some(&(index, next)) = chars.peek() {
+                let same_kind = if is_number {
+                    next.is_ascii_digit()
+                } else {
+                    next.is_alphanumeric() || next == '_'
+                };
+                if !same_kind {
+                    break;
+                }
+                end = index + next.len_utf8();
+                chars.next();
+            }
+
+            let text = &input[start..end];
+            match text.parse::<i64>() {
+                Ok(number) if is_number => tokens.push(Token::Number(number)),
+                _ => tokens.push(Token::Word(text)),
             }
-            self.position += 1;
+        } else {
+            tokens.push(Token::Punct(c));
+        }
+    }
+
+    tokens
+}
+
+// 20. Safe truncation - the returned prefix borrows from the input
+pub fn truncate_str(s: &str, max_bytes: usize) -> &str {
+    if s.len() <= max_bytes {
+        return s;
+    }
+
+    // Slicing in the middle of a multibyte char panics, so back up to a boundary
+    let mut end = max_bytes;
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    &s[..end]
+}
+
+// 21. Longest of many - the result borrows from the slice's contents, not the slice
+pub fn longest_of<'a>(strings: &[&'a str]) -> Option<&'a str> {
+    let mut longest: Option<&'a str> = None;
+    for &s in strings {
+        // Strictly longer only, so the first of equally long strings wins
+        if longest.is_none_or(|current| s.len() > current.len()) {
+            longest = Some(s);
         }
     }
+    longest
 }
 
 // ===========================
 // MAIN FUNCTION WITH EXAMPLES
 // ===========================
 
+#[allow(clippy::approx_constant, clippy::while_let_on_iterator)]
 pub fn run_lifetimes_examples() {
     println!("=== LIFETIMES EXAMPLES ===\n");
     
@@ -316,13 +552,19 @@ pub fn run_lifetimes_examples() {
     let string2 = "short";
     let result = longest(&string1, string2);
     println!("Longest string: '{}'", result);
+    println!("Longest of many: {:?}", longest_of(&["a", "abc", "ab"]));
     
     // Struct with lifetime
     let title = "The Rust Programming Language";
     let author = "Steve Klabnik and Carol Nichols";
-    let book = Book::new(title, author);
+    let book = Book::new(title, author, 2018);
     println!("Book info: {}", book.get_info());
     println!("Book title: {}", book.get_title());
+    println!("Book (Display): {}", book);
+    
+    let newer = Book::new(title, author, 2023);
+    println!("{} ({}) older than {} ({}): {}", book, book.year, newer, newer.year, book.is_older_than(&newer));
+    println!("Same edition: {}", book == Book::new(title, author, 2018));
     
     println!();
     
@@ -357,6 +599,7 @@ pub fn run_lifetimes_examples() {
     int_wrapper.print();
     float_wrapper.print();
     println!("Wrapped int value: {}", int_wrapper.get_value());
+    println!("Mapped int value: {}", int_wrapper.map(|n| n * 2));
     
     println!();
     
@@ -371,6 +614,9 @@ pub fn run_lifetimes_examples() {
     let snippet = article.get_snippet();
     println!("Article snippet: {}", snippet);
     
+    let accented = "café crème";
+    println!("'{}' truncated to 4 bytes: '{}'", accented, truncate_str(accented, 4));
+    
     println!();
     
     // Context with multiple lifetimes
@@ -393,12 +639,13 @@ pub fn run_lifetimes_examples() {
     let ref_holder = RefHolder::new(&num1, &num2);
     println!("RefHolder: {:?}", ref_holder);
     println!("Sum of references: {}", ref_holder.get_sum());
+    println!("Max: {}, Min: {}", ref_holder.max(), ref_holder.min());
     
     println!();
     
     // String splitting iterator
     let text = "hello,world,rust,programming";
-    let mut splitter = StrSplit::new(text, ',');
+    let mut splitter = StrSplit::new(text, ",");
     
     println!("Splitting '{}' by comma:", text);
     while let Some(part) = splitter.next() {
@@ -406,10 +653,38 @@ pub fn run_lifetimes_examples() {
     }
     
     // Using collect to get all parts at once
-    let splitter2 = StrSplit::new("a-b-c-d-e", '-');
+    let splitter2 = StrSplit::from_char("a-b-c-d-e", '-');
     let parts: Vec<&str> = splitter2.collect();
     println!("Split parts: {:?}", parts);
     
+    // Multi-character delimiter
+    let path: Vec<&str> = StrSplit::new("std::collections::HashMap", "::").collect();
+    println!("Path segments: {:?}", path);
+    
+    // Multibyte delimiter
+    let marked: Vec<&str> = StrSplit::from_char("a※b※c", '※').collect();
+    println!("Split on '※': {:?}", marked);
+    
+    // Double-ended: grab the last field without walking the whole string
+    let mut fields = StrSplit::new("id,name,email", ",");
+    println!("Last field: {:?}, first field: {:?}", fields.next_back(), fields.next());
+    
+    // Limited split: the value may itself contain '='
+    let setting: Vec<&str> = StrSplit::with_limit("query=a=b", "=", 2).collect();
+    println!("Key/value: {:?}", setting);
+
+    // Extracting a column from CSV text
+    let csv = "name,age,city\nAlice,30,Paris\nBob\nCarol,25,Rome";
+    let ages = extract_column(csv, 1);
+    println!("Column 1 of CSV: {:?}", ages);
+
+    // Owned split - the parts outlive the source string
+    let owned_parts: Vec<String> = {
+        let source = String::from("x;y;z");
+        OwnedSplit::new(source, ';').collect()
+    };
+    println!("Owned split parts: {:?}", owned_parts);
+
     println!();
     
     // Container with lifetime
@@ -421,6 +696,13 @@ pub fn run_lifetimes_examples() {
         println!("Item at index 2: {}", item);
     }
     
+    let doubled: Vec<i32> = container.iter().map(|n| n * 2).collect();
+    println!("Doubled: {:?}", doubled);
+    for item in &container {
+        print!("{} ", item);
+    }
+    println!();
+    
     // Container with strings
     let words = vec!["hello", "world", "rust", "lifetimes"];
     let word_container = Container::new(&words);
@@ -433,10 +715,22 @@ pub fn run_lifetimes_examples() {
     let mut parser = Parser::new(input);
     
     println!("Parsing '{}' word by word:", input);
+    println!("  Peeked first word: {:?}", parser.peek_word());
     while let Some(word) = parser.parse_word() {
         println!("  Parsed word: '{}'", word);
     }
     
+    let mut numbers = Parser::new("42 apples");
+    println!("Parsed integer: {:?}", numbers.parse_integer());
+    println!("Parsed integer: {:?}", numbers.parse_integer());
+    
+    let messy = "  hello   world  rust ";
+    println!("Tokenized {:?}: {:?}", messy, Parser::new(messy).tokenize());
+
+    // Tokenizer example
+    let source = "foo 42, bar";
+    println!("Tokens of '{}': {:?}", source, tokenize(source));
+    
     println!();
     
     // Closure with lifetime
@@ -461,4 +755,220 @@ pub fn run_lifetimes_examples() {
     }
     // long_lived is still valid here
     println!("Long lived string is still valid: '{}'", long_lived);
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extract_column_skips_short_rows() {
+        let csv = "name,age\nalice,30\nbob\ncarol,41";
+        assert_eq!(extract_column(csv, 1), vec!["age", "30", "41"]);
+    }
+
+    #[test]
+    fn str_split_multi_char_delimiter() {
+        let parts: Vec<&str> = StrSplit::new("a::b::c", "::").collect();
+        assert_eq!(parts, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn str_split_missing_delimiter_yields_whole_string() {
+        let parts: Vec<&str> = StrSplit::new("abc", ",").collect();
+        assert_eq!(parts, vec!["abc"]);
+    }
+
+    #[test]
+    fn str_split_multibyte_char_delimiter() {
+        let text = "一※二※三";
+        let parts: Vec<&str> = StrSplit::from_char(text, '※').collect();
+        assert_eq!(parts, vec!["一", "二", "三"]);
+        assert_eq!(parts, text.split('※').collect::<Vec<&str>>());
+    }
+
+    #[test]
+    fn str_split_next_and_next_back_meet_in_the_middle() {
+        let mut split = StrSplit::new("a,b,c,d", ",");
+        assert_eq!(split.next(), Some("a"));
+        assert_eq!(split.next_back(), Some("d"));
+        assert_eq!(split.next(), Some("b"));
+        assert_eq!(split.next_back(), Some("c"));
+        assert_eq!(split.next(), None);
+        assert_eq!(split.next_back(), None);
+    }
+
+    #[test]
+    fn str_split_next_back_trailing_empty_segment() {
+        let mut split = StrSplit::new("a,b,", ",");
+        assert_eq!(split.next_back(), Some(""));
+        assert_eq!(split.next(), Some("a"));
+        assert_eq!(split.next_back(), Some("b"));
+        assert_eq!(split.next(), None);
+    }
+
+    #[test]
+    fn str_split_with_limit_matches_splitn() {
+        let text = "a,b,c";
+        for limit in [1, 2, 5] {
+            let parts: Vec<&str> = StrSplit::with_limit(text, ",", limit).collect();
+            assert_eq!(parts, text.splitn(limit, ',').collect::<Vec<&str>>());
+        }
+        assert_eq!(StrSplit::with_limit(text, ",", 1).collect::<Vec<&str>>(), vec!["a,b,c"]);
+        assert_eq!(StrSplit::with_limit(text, ",", 2).collect::<Vec<&str>>(), vec!["a", "b,c"]);
+        assert_eq!(StrSplit::with_limit(text, ",", 5).collect::<Vec<&str>>(), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn str_split_with_limit_from_the_back() {
+        let mut split = StrSplit::with_limit("a,b,c,d", ",", 3);
+        assert_eq!(split.next_back(), Some("c,d"));
+        assert_eq!(split.next(), Some("a"));
+        assert_eq!(split.next_back(), Some("b"));
+        assert_eq!(split.next(), None);
+    }
+
+    #[test]
+    fn parser_handles_long_multibyte_input() {
+        let text = "héllo wörld 日本語 ※ café ".repeat(2_000);
+        let words = Parser::new(&text).tokenize();
+        assert_eq!(words.len(), 10_000);
+        assert_eq!(words, text.split_whitespace().collect::<Vec<&str>>());
+    }
+
+    #[test]
+    fn peek_then_parse_returns_same_word() {
+        let mut parser = Parser::new("hello world");
+        assert_eq!(parser.peek_word(), Some("hello"));
+        assert_eq!(parser.peek_word(), Some("hello"));
+        assert_eq!(parser.parse_word(), Some("hello"));
+        assert_eq!(parser.peek_word(), Some("world"));
+    }
+
+    #[test]
+    fn parse_integer_cases() {
+        let mut parser = Parser::new("42 -7 abc");
+        assert_eq!(parser.parse_integer(), Ok(42));
+        assert_eq!(parser.parse_integer(), Ok(-7));
+        assert_eq!(parser.parse_integer(), Err("expected integer, found 'abc'".to_string()));
+        // A failed parse leaves the word in place
+        assert_eq!(parser.parse_word(), Some("abc"));
+        assert_eq!(parser.parse_integer(), Err("unexpected end of input".to_string()));
+    }
+
+    #[test]
+    fn parser_tokenize_skips_extra_whitespace() {
+        let words = Parser::new("  hello   world  rust ").tokenize();
+        assert_eq!(words, vec!["hello", "world", "rust"]);
+    }
+
+    #[test]
+    fn first_word_stops_at_any_whitespace() {
+        assert_eq!(first_word("hello\tworld"), "hello");
+        assert_eq!(first_word("hello\nworld"), "hello");
+        assert_eq!(first_word("hello"), "hello");
+    }
+
+    #[test]
+    fn longest_of_picks_longest() {
+        assert_eq!(longest_of(&["a", "abc", "ab"]), Some("abc"));
+    }
+
+    #[test]
+    fn longest_of_tie_returns_first() {
+        assert_eq!(longest_of(&["ab", "cd", "e"]), Some("ab"));
+    }
+
+    #[test]
+    fn longest_of_empty_is_none() {
+        assert_eq!(longest_of(&[]), None);
+    }
+
+    #[test]
+    fn book_display_matches_get_info() {
+        let book = Book::new("Dune", "Frank Herbert", 1965);
+        assert_eq!(format!("{}", book), "Dune by Frank Herbert");
+        assert_eq!(book.get_info(), "Dune by Frank Herbert");
+    }
+
+    #[test]
+    fn book_is_older_than() {
+        let old = Book::new("Dune", "Frank Herbert", 1965);
+        let title = String::from("Neuromancer");
+        let newer = Book::new(&title, "William Gibson", 1984);
+        assert!(old.is_older_than(&newer));
+        assert!(!newer.is_older_than(&old));
+        assert!(!old.is_older_than(&old));
+    }
+
+    #[test]
+    fn books_equal_on_title_author_and_year() {
+        let book = Book::new("Dune", "Frank Herbert", 1965);
+        assert_eq!(book, Book::new("Dune", "Frank Herbert", 1965));
+        assert_ne!(book, Book::new("Dune", "Frank Herbert", 1966));
+        assert_ne!(book, Book::new("Dune Messiah", "Frank Herbert", 1965));
+    }
+
+    #[test]
+    fn container_iterates_by_reference() {
+        let data = vec![1, 2, 3];
+        let container = Container::new(&data);
+
+        let mut seen = Vec::new();
+        for item in &container {
+            seen.push(*item);
+        }
+        assert_eq!(seen, data);
+        assert_eq!(container.iter().copied().collect::<Vec<i32>>(), data);
+    }
+
+    #[test]
+    fn wrapper_map_borrows_value() {
+        let number = 21;
+        let wrapper = Wrapper::new(&number);
+        assert_eq!(wrapper.map(|n| n * 2), 42);
+
+        let text = String::from("hello");
+        let wrapper = Wrapper::new(&text);
+        assert_eq!(wrapper.map(|s| s.len()), 5);
+        // The wrapper is still usable and still points at the original value
+        assert_eq!(wrapper.get_value(), "hello");
+    }
+
+    #[test]
+    fn ref_holder_max_and_min() {
+        let (big, small) = (200, 100);
+        let holder = RefHolder::new(&big, &small);
+        assert_eq!((holder.max(), holder.min()), (200, 100));
+
+        let holder = RefHolder::new(&small, &big);
+        assert_eq!((holder.max(), holder.min()), (200, 100));
+
+        let holder = RefHolder::new(&big, &big);
+        assert_eq!((holder.max(), holder.min()), (200, 200));
+    }
+
+    #[test]
+    fn owned_split_parts_outlive_source() {
+        let parts: Vec<String> = {
+            let source = String::from("red,green,blue");
+            OwnedSplit::new(source, ',').collect()
+        }; // source and the splitter are gone here
+        assert_eq!(parts, vec!["red", "green", "blue"]);
+    }
+
+    #[test]
+    fn tokenize_words_numbers_and_punctuation() {
+        assert_eq!(
+            tokenize("foo 42, bar"),
+            vec![Token::Word("foo"), Token::Number(42), Token::Punct(','), Token::Word("bar")]
+        );
+    }
+
+    #[test]
+    fn truncate_str_backs_up_to_char_boundary() {
+        // 'é' takes bytes 1..3, so a limit of 2 would cut it in half
+        assert_eq!(truncate_str("héllo", 2), "h");
+        assert_eq!(truncate_str("héllo", 3), "hé");
+        assert_eq!(truncate_str("héllo", 10), "héllo");
+    }
+}
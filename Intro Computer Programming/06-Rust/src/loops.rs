@@ -45,4 +45,271 @@ pub fn r#main() {
             println!("i: {}, j: {}", i, j);
         }
     }
-}
\ No newline at end of file
+
+    // Example of a rolling window sum over a slice
+    let data = [1, 2, 3, 4, 5];
+    println!("Window sums (k=2): {:?}", windowed_sums(&data, 2));
+
+    // Example of scanning neighbours to find peaks
+    let signal = [1, 3, 2, 4, 1];
+    println!("Peaks of {:?} at indices: {:?}", signal, find_peaks(&signal));
+
+    // Example of a loop that stops on overflow
+    println!("20! = {:?}, 21! = {:?}", factorial(20), factorial(21));
+
+    // Example of nested while loops over the digits of a number
+    println!("Digit sum of 12345: {}", digit_sum(12345));
+    println!("Digital root of 9875: {}", digital_root(9875));
+
+    // Example of a while loop with an unknown number of steps
+    println!("Collatz sequence from 6: {:?}", collatz(6));
+
+    // Example of counting in one loop and rebuilding the output in another
+    println!("Counting sort: {:?}", counting_sort(&[3, 1, 2, 3, 0, 1], 3));
+
+    // Example of looping over every window and sorting a copy of it
+    println!("Moving median (k=3): {:?}", moving_median(&[5.0, 1.0, 3.0, 2.0, 4.0], 3));
+
+    // Example of a while loop that runs until enough results are found
+    println!("First 10 primes: {:?}", first_n_primes(10));
+}
+
+// Sum of every window of size k using a rolling sum:
+// each step subtracts the element leaving and adds the one entering
+pub fn windowed_sums(data: &[i64], k: usize) -> Vec<i64> {
+    if k == 0 || k > data.len() {
+        return Vec::new();
+    }
+
+    let mut sum: i64 = data[..k].iter().sum();
+    let mut sums = vec![sum];
+
+    for i in k..data.len() {
+        sum += data[i] - data[i - k];
+        sums.push(sum);
+    }
+
+    sums
+}
+
+// Indices of local maxima: values strictly greater than both neighbours.
+// The first and last elements only have one neighbour so they are skipped
+pub fn find_peaks(data: &[i32]) -> Vec<usize> {
+    let mut peaks = Vec::new();
+
+    for i in 1..data.len().saturating_sub(1) {
+        if data[i] > data[i - 1] && data[i] > data[i + 1] {
+            peaks.push(i);
+        }
+    }
+
+    peaks
+}
+
+// n! with checked_mul: returns None instead of overflowing (21! no longer fits in u64)
+pub fn factorial(n: u32) -> Option<u64> {
+    let mut result: u64 = 1;
+
+    for i in 2..=n as u64 {
+        result = result.checked_mul(i)?;
+    }
+
+    Some(result)
+}
+
+// Peel off the last digit with % 10 and drop it with / 10 until nothing is left
+pub fn digit_sum(mut n: u64) -> u64 {
+    let mut sum = 0;
+
+    while n > 0 {
+        sum += n % 10;
+        n /= 10;
+    }
+
+    sum
+}
+
+// Keep summing digits until a single digit remains
+pub fn digital_root(mut n: u64) -> u64 {
+    while n >= 10 {
+        n = digit_sum(n);
+    }
+
+    n
+}
+
+// Collatz: halve even numbers, turn odd ones into 3n + 1, stop at 1.
+// 0 never reaches 1 so it gives an empty sequence, and if 3n + 1 would
+// overflow u64 the steps computed so far are returned
+pub fn collatz(start: u64) -> Vec<u64> {
+    if start == 0 {
+        return Vec::new();
+    }
+
+    let mut n = start;
+    let mut sequence = vec![n];
+
+    while n != 1 {
+        n = if n.is_multiple_of(2) {
+            n / 2
+        } else {
+            match n.checked_mul(3).and_then(|tripled| tripled.checked_add(1)) {
+                Some(next) => next,
+                None => break,
+            }
+        };
+        sequence.push(n);
+    }
+
+    sequence
+}
+
+// Counting sort for values in 0..=max: no comparisons, just one pass to count
+// how often each value occurs and one pass over the counts to write them out.
+// Panics if an item is larger than max
+pub fn counting_sort(items: &[u32], max: u32) -> Vec<u32> {
+    let mut counts = vec![0usize; max as usize + 1];
+    for &item in items {
+        assert!(item <= max, "{} is larger than max {}", item, max);
+        counts[item as usize] += 1;
+    }
+
+    let mut sorted = Vec::with_capacity(items.len());
+    for (value, &count) in counts.iter().enumerate() {
+        for _ in 0..count {
+            sorted.push(value as u32);
+        }
+    }
+
+    sorted
+}
+
+// Median of every window of size `window`. Unlike a sum, a median can't be
+// updated by adding and removing one value, so each window is copied and sorted.
+// Even-sized windows average their two middle values
+pub fn moving_median(data: &[f64], window: usize) -> Vec<f64> {
+    if window == 0 || window > data.len() {
+        return Vec::new();
+    }
+
+    let mut medians = Vec::with_capacity(data.len() - window + 1);
+    for slice in data.windows(window) {
+        let mut sorted = slice.to_vec();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+
+        let mid = window / 2;
+        let median = if window.is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2.0
+        } else {
+            sorted[mid]
+        };
+        medians.push(median);
+    }
+
+    medians
+}
+
+// First n primes by trial division. There is no upper bound to sieve up to,
+// so candidates are tested one by one until n primes are found. A candidate
+// only needs dividing by the primes found so far, up to its square root
+pub fn first_n_primes(n: usize) -> Vec<u64> {
+    let mut primes: Vec<u64> = Vec::with_capacity(n);
+    let mut candidate = 2;
+
+    while primes.len() < n {
+        let is_prime = primes
+            .iter()
+            .take_while(|&&p| p * p <= candidate)
+            .all(|&p| candidate % p != 0);
+        if is_prime {
+            primes.push(candidate);
+        }
+        candidate += 1;
+    }
+
+    primes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn windowed_sums_of_pairs() {
+        assert_eq!(windowed_sums(&[1, 2, 3, 4, 5], 2), vec![3, 5, 7, 9]);
+        assert!(windowed_sums(&[1, 2], 3).is_empty());
+        assert!(windowed_sums(&[1, 2], 0).is_empty());
+    }
+
+    #[test]
+    fn find_peaks_indices() {
+        assert_eq!(find_peaks(&[1, 3, 2, 4, 1]), vec![1, 3]);
+    }
+
+    #[test]
+    fn find_peaks_monotonic_has_none() {
+        assert!(find_peaks(&[1, 2, 3, 4]).is_empty());
+        assert!(find_peaks(&[4, 3, 2, 1]).is_empty());
+    }
+
+    #[test]
+    fn factorial_values_and_overflow() {
+        assert_eq!(factorial(0), Some(1));
+        assert_eq!(factorial(5), Some(120));
+        assert_eq!(factorial(20), Some(2_432_902_008_176_640_000));
+        assert_eq!(factorial(21), None);
+    }
+
+    #[test]
+    fn digit_sum_and_digital_root() {
+        assert_eq!(digit_sum(12345), 15);
+        assert_eq!(digital_root(9875), 2);
+        assert_eq!(digital_root(0), 0);
+    }
+
+    #[test]
+    fn collatz_from_six() {
+        assert_eq!(collatz(6), vec![6, 3, 10, 5, 16, 8, 4, 2, 1]);
+        assert_eq!(collatz(1), vec![1]);
+        assert!(collatz(0).is_empty());
+    }
+
+    #[test]
+    fn counting_sort_matches_std_sort() {
+        let items = [5, 3, 3, 0, 9, 1, 5, 5, 2];
+        let mut expected = items.to_vec();
+        expected.sort();
+        assert_eq!(counting_sort(&items, 9), expected);
+    }
+
+    #[test]
+    fn counting_sort_empty_input() {
+        assert!(counting_sort(&[], 4).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "5 is larger than max 4")]
+    fn counting_sort_panics_above_max() {
+        counting_sort(&[1, 5], 4);
+    }
+
+    #[test]
+    fn moving_median_window_three() {
+        // Windows: [5,1,3] -> 3, [1,3,2] -> 2, [3,2,4] -> 3
+        assert_eq!(moving_median(&[5.0, 1.0, 3.0, 2.0, 4.0], 3), vec![3.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn moving_median_even_window_and_invalid_windows() {
+        assert_eq!(moving_median(&[1.0, 2.0, 4.0], 2), vec![1.5, 3.0]);
+        assert!(moving_median(&[1.0, 2.0], 0).is_empty());
+        assert!(moving_median(&[1.0, 2.0], 3).is_empty());
+    }
+
+    #[test]
+    fn first_n_primes_small_counts() {
+        assert!(first_n_primes(0).is_empty());
+        assert_eq!(first_n_primes(1), vec![2]);
+        assert_eq!(first_n_primes(10), vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    }
+}
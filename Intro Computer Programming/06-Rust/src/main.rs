@@ -7,6 +7,7 @@ use std::io;
 // Existing modules
 mod conditionals;
 mod datatypes_variables;
+mod errors;
 mod functions;
 mod hashmaps;
 mod loops;
@@ -17,6 +18,7 @@ mod operators;
 mod r#impl;
 mod generics;
 mod traits;
+#[path = "lifetime.rs"]
 mod lifetimes;
 
 // nested modules
@@ -66,8 +68,18 @@ fn main() {
     hashmaps();
     
     println!("===================================Learning Projects====================================");
-    task1::median_mode();
-    task1::pig_latin("apple");
+    let numbers = vec![1, 2, 3, 4, 5, 6, 1, 2, 2, 3, 5, 2, 2, 2, 2, 3, 5];
+    if let Some((median, mode)) = task1::median_mode(&numbers) {
+        println!("Median: {}", median);
+        println!("Mode: {}", mode);
+    }
+    println!("All modes: {:?}", task1::modes(&numbers));
+    if let (Some(mean), Some(variance)) = (task1::mean(&numbers), task1::variance(&numbers)) {
+        println!("Mean: {:.2}", mean);
+        println!("Variance: {:.2}", variance);
+    }
+    task1::median_mode_interactive();
+    println!("Pig Latin: {}", task1::pig_latin("apple"));
     task1::alphabetical_employees_interface();
     
     // New advanced topics
@@ -1,6 +1,8 @@
 // operators.rs - Rust Operators Examples
 // This file demonstrates all operators covered in the operators.md guide
 #![allow(unused)]
+// The precedence examples combine literals on purpose to show evaluation order
+#[allow(clippy::approx_constant, clippy::eq_op, clippy::nonminimal_bool)]
 pub fn operators() {
     println!("=== RUST OPERATORS EXAMPLES ===\n");
     
@@ -134,6 +136,16 @@ pub fn operators() {
     let right_shift = bit_a >> 1;
     println!("Right shift: {} >> 1 = {} (binary: {:04b})", 
              bit_a, right_shift, right_shift);
+    
+    // Setting, clearing and reading single bits with a newtype
+    let mut bits = Bits(0);
+    bits.set_bit(0);
+    bits.set_bit(3);
+    bits.set_bit(4);
+    bits.clear_bit(4);
+    println!("Bits: {} (bit 3 set: {})", bits, bits.get_bit(3));
+    let lowest: Vec<bool> = bits.iter_bits().take(4).collect();
+    println!("Lowest 4 bits, LSB first: {:?}", lowest);
     println!();
     
     //------------------------------------------- 6. TYPE CASTING (as operator)
@@ -232,6 +244,18 @@ pub fn operators() {
     println!("Can definitely shop: {}", can_shop);
     println!("Can try to shop: {}", can_try_shop);
     
+    // Number theory with % (Euclid's algorithm)
+    println!("gcd(48, 18) = {}", gcd(48, 18));
+    println!("lcm(4, 6) = {}", lcm(4, 6));
+    
+    // Base conversion with / and %
+    println!("255 in base 2: {:?}", to_base(255, 2));
+    println!("255 in base 16: {:?}", to_base(255, 16));
+    println!("\"zz\" from base 36: {:?}", from_base("zz", 36));
+    
+    // Rolling hash with *, + and % (Rabin-Karp substring search)
+    println!("rabin_karp(\"hello world\", \"world\") = {:?}", rabin_karp("hello world", "world"));
+    
     println!("\n=== END OF OPERATORS EXAMPLES ===");
 }
 
@@ -298,4 +322,276 @@ fn practice_exercises() {
     // let has_number = true;
     // let is_strong = password_length >= 8 && has_special_char && has_number;
     // println!("Password is strong: {}", is_strong);
-}
\ No newline at end of file
+}
+
+// Number theory helpers built from the remainder operator
+// Euclid's algorithm: gcd(a, b) = gcd(b, a % b) until b is 0
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let remainder = a % b;
+        a = b;
+        b = remainder;
+    }
+    a
+}
+
+// Dividing by the gcd before multiplying keeps the intermediate value small.
+// Panics if the lcm itself does not fit in u64; use checked_lcm to get None instead
+pub fn lcm(a: u64, b: u64) -> u64 {
+    checked_lcm(a, b).expect("lcm overflowed u64")
+}
+
+pub fn checked_lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+// Base conversion: repeatedly take `n % base` as the next digit (right to left)
+// and divide by base, for any base from 2 to 36 (digits 0-9 then a-z)
+pub fn to_base(mut n: u32, base: u32) -> Option<String> {
+    if !(2..=36).contains(&base) {
+        return None;
+    }
+    if n == 0 {
+        return Some(String::from("0"));
+    }
+
+    let mut digits = Vec::new();
+    while n > 0 {
+        digits.push(char::from_digit(n % base, base)?);
+        n /= base;
+    }
+    Some(digits.iter().rev().collect())
+}
+
+// The reverse: multiply the running value by base and add each digit,
+// returning None for invalid digits or if the value overflows u32
+pub fn from_base(s: &str, base: u32) -> Option<u32> {
+    if !(2..=36).contains(&base) || s.is_empty() {
+        return None;
+    }
+
+    let mut value: u32 = 0;
+    for c in s.chars() {
+        let digit = c.to_digit(base)?;
+        value = value.checked_mul(base)?.checked_add(digit)?;
+    }
+    Some(value)
+}
+
+const RABIN_KARP_BASE: u64 = 256;
+const RABIN_KARP_MODULUS: u64 = 1_000_000_007;
+
+// The bytes read as digits of a base-256 number, reduced modulo the prime
+fn rabin_karp_hash(bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .fold(0, |h, &b| (h * RABIN_KARP_BASE + b as u64) % RABIN_KARP_MODULUS)
+}
+
+// Rabin-Karp substring search: hash the needle once, then slide a window over
+// the haystack, updating its hash in O(1) by removing the leading byte and
+// adding the next one. All arithmetic is done modulo a prime so it never
+// overflows. Equal hashes can still be a collision, so the bytes are compared
+// before reporting a match. Returns a byte index, like str::find
+pub fn rabin_karp(haystack: &str, needle: &str) -> Option<usize> {
+    const BASE: u64 = RABIN_KARP_BASE;
+    const MODULUS: u64 = RABIN_KARP_MODULUS;
+
+    let text = haystack.as_bytes();
+    let pattern = needle.as_bytes();
+    let n = pattern.len();
+    if n == 0 {
+        return Some(0);
+    }
+    if n > text.len() {
+        return None;
+    }
+
+    let pattern_hash = rabin_karp_hash(pattern);
+    let mut window_hash = rabin_karp_hash(&text[..n]);
+
+    // Weight of the leading byte in the window: BASE^(n - 1) % MODULUS
+    let mut leading_weight = 1;
+    for _ in 1..n {
+        leading_weight = leading_weight * BASE % MODULUS;
+    }
+
+    for start in 0..=text.len() - n {
+        if window_hash == pattern_hash && &text[start..start + n] == pattern {
+            return Some(start);
+        }
+        if start + n < text.len() {
+            // Adding MODULUS before subtracting keeps the value from going negative
+            let without_leading =
+                (window_hash + MODULUS - text[start] as u64 * leading_weight % MODULUS) % MODULUS;
+            window_hash = (without_leading * BASE + text[start + n] as u64) % MODULUS;
+        }
+    }
+    None
+}
+
+// A u32 viewed as 32 individual bits. `1 << i` is a mask with only bit i set:
+// OR turns the bit on, AND with the inverted mask turns it off, AND tests it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bits(pub u32);
+
+impl Bits {
+    // Shifting a u32 by 32 or more is an error, so bad indices panic clearly
+    fn mask(i: u32) -> u32 {
+        assert!(i < 32, "bit index {} is out of range 0..32", i);
+        1 << i
+    }
+
+    pub fn get_bit(&self, i: u32) -> bool {
+        self.0 & Bits::mask(i) != 0
+    }
+
+    pub fn set_bit(&mut self, i: u32) {
+        self.0 |= Bits::mask(i);
+    }
+
+    pub fn clear_bit(&mut self, i: u32) {
+        self.0 &= !Bits::mask(i);
+    }
+
+    // Least significant bit first
+    pub fn iter_bits(&self) -> impl Iterator<Item = bool> {
+        let value = self.0;
+        (0..32).map(move |i| (value >> i) & 1 == 1)
+    }
+}
+
+// Most significant bit first, padded with zeros to all 32 bits
+impl std::fmt::Display for Bits {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:032b}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_examples() {
+        assert_eq!(gcd(48, 18), 6);
+        assert_eq!(gcd(18, 48), 6);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(gcd(5, 0), 5);
+        assert_eq!(gcd(0, 0), 0);
+    }
+
+    #[test]
+    fn lcm_examples() {
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(lcm(u64::MAX, 1), u64::MAX);
+    }
+
+    #[test]
+    fn checked_lcm_reports_overflow() {
+        assert_eq!(checked_lcm(4, 6), Some(12));
+        assert_eq!(checked_lcm(u64::MAX, u64::MAX - 1), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "lcm overflowed u64")]
+    fn lcm_panics_on_overflow() {
+        lcm(u64::MAX, u64::MAX - 1);
+    }
+
+    #[test]
+    fn base_conversion_round_trips() {
+        for base in [2, 16, 36] {
+            for n in [0, 1, 35, 255, 1_000_000, u32::MAX] {
+                let digits = to_base(n, base).unwrap();
+                assert_eq!(from_base(&digits, base), Some(n));
+            }
+        }
+        assert_eq!(to_base(255, 2), Some("11111111".to_string()));
+        assert_eq!(to_base(255, 16), Some("ff".to_string()));
+        assert_eq!(from_base("zz", 36), Some(1295));
+    }
+
+    #[test]
+    fn base_conversion_rejects_bad_input() {
+        assert_eq!(to_base(10, 1), None);
+        assert_eq!(to_base(10, 37), None);
+        assert_eq!(from_base("10", 0), None);
+        assert_eq!(from_base("10", 37), None);
+        assert_eq!(from_base("2", 2), None);
+        assert_eq!(from_base("", 10), None);
+        assert_eq!(from_base("100000000", 16), None);
+    }
+
+    #[test]
+    fn rabin_karp_matches_str_find() {
+        let cases = [
+            ("hello world", "world"),
+            ("hello world", "xyz"),
+            ("abc", ""),
+            ("", "a"),
+            ("abc", "abcd"),
+            ("abababab", "bab"),
+            ("héllo wörld", "wö"),
+        ];
+        for (haystack, needle) in cases {
+            assert_eq!(rabin_karp(haystack, needle), haystack.find(needle), "{:?} in {:?}", needle, haystack);
+        }
+    }
+
+    #[test]
+    fn rabin_karp_repetitive_input() {
+        let haystack = format!("{}b", "a".repeat(1_000));
+        for needle in ["aab", "ab", "b", "aaab", "ba"] {
+            assert_eq!(rabin_karp(&haystack, needle), haystack.find(needle));
+        }
+    }
+
+    #[test]
+    fn rabin_karp_rejects_hash_collisions() {
+        // Different strings with the same hash under the real base and modulus
+        let (decoy, needle) = ("rjpqfddq", "xqqhmxpv");
+        assert_eq!(rabin_karp_hash(decoy.as_bytes()), rabin_karp_hash(needle.as_bytes()));
+
+        // Only the byte comparison tells the decoy window apart from a match
+        assert_eq!(rabin_karp(decoy, needle), None);
+        assert_eq!(rabin_karp(&format!("--{}--", decoy), needle), None);
+        assert_eq!(rabin_karp(&format!("{}-{}", decoy, needle), needle), Some(9));
+    }
+
+    #[test]
+    fn bits_set_clear_and_get() {
+        let mut bits = Bits(0);
+        bits.set_bit(0);
+        bits.set_bit(5);
+        bits.set_bit(31);
+        bits.clear_bit(5);
+        assert!(bits.get_bit(0));
+        assert!(!bits.get_bit(5));
+        assert!(bits.get_bit(31));
+        assert_eq!(bits, Bits(1 | 1 << 31));
+    }
+
+    #[test]
+    fn bits_iterate_lsb_first() {
+        let bits: Vec<bool> = Bits(0b1101).iter_bits().collect();
+        assert_eq!(bits.len(), 32);
+        assert_eq!(&bits[..5], &[true, false, true, true, false]);
+    }
+
+    #[test]
+    fn bits_display_is_32_chars() {
+        assert_eq!(Bits(0b1001).to_string(), format!("{}1001", "0".repeat(28)));
+        assert_eq!(Bits(u32::MAX).to_string(), "1".repeat(32));
+    }
+
+    #[test]
+    #[should_panic(expected = "bit index 32 is out of range 0..32")]
+    fn bits_index_out_of_range_panics() {
+        Bits(0).set_bit(32);
+    }
+}
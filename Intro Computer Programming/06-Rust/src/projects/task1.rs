@@ -1,19 +1,48 @@
 use std::collections::HashMap;
+use std::hash::Hash;
+use std::fs;
 use std::io::{self, Write};
 
 
-pub fn median_mode() {
-    let mut numbers: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 1, 2, 2, 3, 5, 2, 2, 2, 2, 3, 5];
-    let mut count_map: HashMap<i32, i32> = HashMap::new();
+// Lets the statistics functions work over any integer type.
+// `Into<f64>` is not implemented for i64/u64 (the conversion can lose
+// precision), so we spell out the conversion for each integer type.
+pub trait ToF64: Copy {
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_to_f64 {
+    ($($t:ty),*) => {
+        $(impl ToF64 for $t {
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        })*
+    };
+}
+
+impl_to_f64!(i8, i16, i32, i64, u8, u16, u32, u64, isize, usize);
+
+
+pub fn median_mode<T>(numbers: &[T]) -> Option<(f64, T)>
+where
+    T: ToF64 + Ord + Hash,
+{
+    if numbers.is_empty() {
+        return None;
+    }
+
+    let mut numbers: Vec<T> = numbers.to_vec();
+    let mut count_map: HashMap<T, usize> = HashMap::new();
 
     numbers.sort(); 
 
     // 📊 Median
     let middle_index = numbers.len() / 2;
-    let median = if numbers.len() % 2 == 0 {
-        (numbers[middle_index - 1] + numbers[middle_index]) as f64 / 2.0
+    let median = if numbers.len().is_multiple_of(2) {
+        (numbers[middle_index - 1].to_f64() + numbers[middle_index].to_f64()) / 2.0
     } else {
-        numbers[middle_index] as f64
+        numbers[middle_index].to_f64()
     };
 
     // 🔁 Mode
@@ -29,29 +58,302 @@ pub fn median_mode() {
         }
     }
 
-    println!("Median: {}", median);
-    println!("Mode: {}", mode);
+    Some((median, mode))
+}
+
+
+pub fn modes<T>(numbers: &[T]) -> Vec<T>
+where
+    T: Copy + Ord + Hash,
+{
+    let mut count_map: HashMap<T, usize> = HashMap::new();
+
+    for &num in numbers {
+        *count_map.entry(num).or_insert(0) += 1;
+    }
+
+    // 🔁 Every value sharing the highest count
+    let max_count = count_map.values().copied().max().unwrap_or(0);
+    let mut modes: Vec<T> = count_map
+        .into_iter()
+        .filter(|&(_, count)| count == max_count)
+        .map(|(num, _)| num)
+        .collect();
+
+    modes.sort();
+    modes
+}
+
+
+pub fn mean<T: ToF64>(numbers: &[T]) -> Option<f64> {
+    if numbers.is_empty() {
+        return None;
+    }
+
+    let sum: f64 = numbers.iter().map(|&num| num.to_f64()).sum();
+    Some(sum / numbers.len() as f64)
+}
+
+
+pub fn variance<T: ToF64>(numbers: &[T]) -> Option<f64> {
+    let mean = mean(numbers)?;
+
+    // 📐 Population variance: average squared distance from the mean
+    let squared_diffs: f64 = numbers
+        .iter()
+        .map(|&num| (num.to_f64() - mean).powi(2))
+        .sum();
+    Some(squared_diffs / numbers.len() as f64)
 }
 
 
+pub fn median_mode_interactive() {
+    print!("Enter integers separated by spaces: ");
+    io::stdout().flush().unwrap();
+    let mut input = String::new();
+    io::stdin().read_line(&mut input).expect("Failed to read input");
 
-pub fn pig_latin(sentence: &str) {
+    let mut numbers: Vec<i32> = Vec::new();
+    for token in input.split_whitespace() {
+        match token.parse::<i32>() {
+            Ok(num) => numbers.push(num),
+            Err(_) => println!("❌ skipping invalid number '{}'", token),
+        }
+    }
+
+    match median_mode(&numbers) {
+        Some((median, mode)) => {
+            println!("Median: {}", median);
+            println!("Mode: {}", mode);
+        }
+        None => println!("❌ No valid numbers entered."),
+    }
+}
+
+
+
+pub fn pig_latin(sentence: &str) -> String {
     let mut pig_latin_sentence = String::new();
-    const VOWELS: [char; 10] = ['a', 'e', 'i', 'o', 'u', 'A', 'E', 'I', 'O', 'U'];
+    const PUNCTUATION: [char; 6] = [',', '.', '!', '?', ';', ':'];
     
     for word in sentence.split_whitespace() {
-        let first_char = word.chars().next().unwrap();
-        if VOWELS.contains(&first_char) {
-            pig_latin_sentence.push_str(&format!("{}-hay ", word));
+        // ✂️ Only trailing punctuation is split off; apostrophes inside stay put
+        let core = word.trim_end_matches(&PUNCTUATION[..]);
+        let punctuation = &word[core.len()..];
+        pig_latin_sentence.push_str(&format!("{}{} ", translate_word(core), punctuation));
+    }
+    pig_latin_sentence.trim_end().to_string()
+}
+
+
+fn translate_word(word: &str) -> String {
+    const VOWELS: [char; 10] = ['a', 'e', 'i', 'o', 'u', 'A', 'E', 'I', 'O', 'U'];
+
+    let Some(first_char) = word.chars().next() else {
+        return String::new();
+    };
+    if VOWELS.contains(&first_char) {
+        return format!("{}-hay", word);
+    }
+
+    // 🔤 Move the whole leading consonant cluster, up to the first vowel.
+    // char_indices gives byte offsets on char boundaries, so multibyte letters are safe
+    let Some(vowel_index) = word.char_indices().find(|(_, c)| VOWELS.contains(c)).map(|(i, _)| i) else {
+        return format!("{}-ay", word);
+    };
+    let (cluster, rest_of_word) = word.split_at(vowel_index);
+
+    if first_char.is_uppercase() {
+        // 🔠 Keep the capital at the front of the translated word
+        format!("{}-{}ay", capitalize(rest_of_word), cluster.to_lowercase())
+    } else {
+        format!("{}-{}ay", rest_of_word, cluster)
+    }
+}
+
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Add { name: String, dept: String },
+    Remove { name: String, dept: String },
+    Show(String),
+    ShowAll,
+    Save(String),
+    Load(String),
+    Export(String),
+    Exit,
+    Invalid(&'static str),
+    Unknown,
+}
+
+
+pub fn parse_command(line: &str) -> Command {
+    let line = line.trim();
+    let (keyword, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+    let rest = rest.trim();
+    let args = split_args(rest);
+
+    match keyword.to_lowercase().as_str() {
+        "exit" if rest.is_empty() => Command::Exit,
+        "add" => match args.as_deref() {
+            Some([name, to, dept]) if to.eq_ignore_ascii_case("to") => Command::Add {
+                name: name.clone(),
+                dept: dept.clone(),
+            },
+            _ => Command::Invalid("Add <Name> to <Department>"),
+        },
+        "remove" => match args.as_deref() {
+            Some([name, from, dept]) if from.eq_ignore_ascii_case("from") => Command::Remove {
+                name: name.clone(),
+                dept: dept.clone(),
+            },
+            _ => Command::Invalid("Remove <Name> from <Department>"),
+        },
+        "show" => match args.as_deref() {
+            Some([all]) if all.eq_ignore_ascii_case("all") => Command::ShowAll,
+            Some([dept]) => Command::Show(dept.clone()),
+            _ => Command::Invalid("Show <Department> or Show All"),
+        },
+        "save" if !rest.is_empty() => Command::Save(rest.to_string()),
+        "save" => Command::Invalid("Save <Path>"),
+        "load" if !rest.is_empty() => Command::Load(rest.to_string()),
+        "load" => Command::Invalid("Load <Path>"),
+        "export" if !rest.is_empty() => Command::Export(rest.to_string()),
+        "export" => Command::Invalid("Export <Path>"),
+        _ => Command::Unknown,
+    }
+}
+
+
+// ✂️ Splits on whitespace, but keeps "quoted spans" together as one argument
+// so names like "John Smith" survive; the quotes themselves are dropped.
+// None for an unclosed quote or a blank argument like "", which can't be saved
+fn split_args(input: &str) -> Option<Vec<String>> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut has_arg = false;
+
+    for c in input.chars() {
+        if c == '"' {
+            in_quotes = !in_quotes;
+            has_arg = true;
+        } else if c.is_whitespace() && !in_quotes {
+            if has_arg {
+                if current.trim().is_empty() {
+                    return None;
+                }
+                args.push(std::mem::take(&mut current));
+                has_arg = false;
+            }
         } else {
-            let rest_of_word = &word[1..];
-            pig_latin_sentence.push_str(&format!("{}-{}ay ", rest_of_word, first_char));
+            current.push(c);
+            has_arg = true;
+        }
+    }
+    if in_quotes {
+        return None;
+    }
+    if has_arg {
+        if current.trim().is_empty() {
+            return None;
         }
+        args.push(current);
     }
-    println!("Pig Latin: {}", pig_latin_sentence.trim());
+
+    Some(args)
 }
 
 
+pub fn apply(company: &mut HashMap<String, Vec<String>>, cmd: &Command) -> String {
+    match cmd {
+        Command::Add { name, dept } => {
+            // The save file uses ':' after the department and ',' between names
+            if name.contains([',', ':']) || dept.contains([',', ':']) {
+                return "❌ Names and departments can't contain ',' or ':'.".to_string();
+            }
+            let dept = department_key(company, dept);
+            let employees = company.entry(dept.clone()).or_default();
+            // Same name may appear in other departments, just not twice in this one
+            if employees.iter().any(|e| e.eq_ignore_ascii_case(name)) {
+                return format!("❌ {} already in {}", name, dept);
+            }
+            employees.push(name.clone());
+            format!("✅ Added {} to {}", name, dept)
+        }
+        Command::Remove { name, dept } => {
+            let dept = department_key(company, dept);
+            let Some(employees) = company.get_mut(&dept) else {
+                return "❌ Department not found.".to_string();
+            };
+            // Department stays in the map even if it becomes empty
+            match employees.iter().position(|e| e.eq_ignore_ascii_case(name)) {
+                Some(index) => {
+                    let removed = employees.remove(index);
+                    format!("✅ Removed {} from {}", removed, dept)
+                }
+                None => format!("❌ {} not found in {}.", name, dept),
+            }
+        }
+        Command::Show(dept) => {
+            let dept = department_key(company, dept);
+            match company.get(&dept) {
+                Some(employees) => format_department(&dept, employees),
+                None => "❌ Department not found.".to_string(),
+            }
+        }
+        Command::ShowAll => {
+            if company.is_empty() {
+                return "📭 No departments yet.".to_string();
+            }
+            let mut departments: Vec<&String> = company.keys().collect();
+            departments.sort();
+            departments
+                .into_iter()
+                .map(|dept| format_department(dept, &company[dept]))
+                .collect::<Vec<String>>()
+                .join("\n")
+        }
+        Command::Save(path) => match save_directory(company, path) {
+            Ok(()) => format!("💾 Saved directory to {}", path),
+            Err(e) => format!("❌ Could not save to {}: {}", path, e),
+        },
+        Command::Load(path) => match load_directory(company, path) {
+            Ok(()) => format!("📥 Loaded directory from {}", path),
+            Err(e) => format!("❌ Could not load {}: {}", path, e),
+        },
+        Command::Export(path) => match fs::write(path, to_csv(company)) {
+            Ok(()) => format!("📤 Exported directory to {}", path),
+            Err(e) => format!("❌ Could not export to {}: {}", path, e),
+        },
+        Command::Exit => "👋 Goodbye!".to_string(),
+        Command::Invalid(usage) => format!("❌ Invalid format. Use: {}", usage),
+        Command::Unknown => "❌ Unknown command.".to_string(),
+    }
+}
+
+
+fn format_department(dept: &str, employees: &[String]) -> String {
+    let mut sorted = employees.to_vec();
+    sorted.sort();
+
+    let mut output = format!("\n📂 Department: {}", dept);
+    for name in sorted {
+        output.push_str(&format!("\n - {}", name));
+    }
+    output
+}
+
 
 pub fn alphabetical_employees_interface() {
     let mut company: HashMap<String, Vec<String>> = HashMap::new();
@@ -59,54 +361,416 @@ pub fn alphabetical_employees_interface() {
     loop {
         println!("\nCommands:");
         println!("  Add <Name> to <Department>");
+        println!("  Remove <Name> from <Department>");
         println!("  Show <Department>");
         println!("  Show All");
+        println!("  Save <Path>");
+        println!("  Load <Path>");
+        println!("  Export <Path>");
         println!("  Exit");
+        println!("  (quote names with spaces: Add \"John Smith\" to \"R&D Team\")");
 
         print!("> ");
         io::stdout().flush().unwrap(); 
         let mut input = String::new();
         io::stdin().read_line(&mut input).expect("Failed to read input");
-        let input = input.trim();
 
-        if input.eq_ignore_ascii_case("exit") {
+        let command = parse_command(&input);
+        println!("{}", apply(&mut company, &command));
+        if command == Command::Exit {
             break;
-        } else if input.to_lowercase().starts_with("add ") {
-            let parts: Vec<&str> = input.split_whitespace().collect();
-            if parts.len() >= 4 && parts[2].eq_ignore_ascii_case("to") {
-                let name = parts[1].to_string();
-                let dept = parts[3].to_string();
-                company.entry(dept.clone()).or_default().push(name.clone());
-                println!("✅ Added {} to {}", name, dept);
-            } else {
-                println!("❌ Invalid format. Use: Add <Name> to <Department>");
-            }
-        } else if input.to_lowercase().starts_with("show all") {
-            for (dept, employees) in &company {
-                let mut sorted = employees.clone();
-                sorted.sort();
-                println!("\n📂 Department: {}", dept);
-                for name in sorted {
-                    println!(" - {}", name);
-                }
-            }
-        } else if input.to_lowercase().starts_with("show ") {
-            let parts: Vec<&str> = input.split_whitespace().collect();
-            if parts.len() == 2 {
-                let dept = parts[1];
-                if let Some(employees) = company.get(dept) {
-                    let mut sorted = employees.clone();
-                    sorted.sort();
-                    println!("\n📂 Department: {}", dept);
-                    for name in sorted {
-                        println!(" - {}", name);
-                    }
-                } else {
-                    println!("❌ Department not found.");
-                }
+        }
+    }
+}
+
+
+// 🔎 Department names are matched case-insensitively: returns the key already
+// stored in the map, or the name as typed if the department is new
+pub fn department_key(company: &HashMap<String, Vec<String>>, dept: &str) -> String {
+    let wanted = dept.to_lowercase();
+    company
+        .keys()
+        .find(|key| key.to_lowercase() == wanted)
+        .cloned()
+        .unwrap_or_else(|| dept.to_string())
+}
+
+
+// 💾 One line per department: `Department: name1, name2`
+pub fn save_directory(company: &HashMap<String, Vec<String>>, path: &str) -> io::Result<()> {
+    let mut departments: Vec<&String> = company.keys().collect();
+    departments.sort();
+
+    let mut contents = String::new();
+    for dept in departments {
+        contents.push_str(&format!("{}: {}\n", dept, company[dept].join(", ")));
+    }
+    fs::write(path, contents)
+}
+
+
+// 📥 Merges the saved departments into the map; departments not in the file are kept
+pub fn load_directory(company: &mut HashMap<String, Vec<String>>, path: &str) -> io::Result<()> {
+    let contents = fs::read_to_string(path)?;
+
+    // Parse every line before touching the map, so a bad line leaves it unchanged
+    let mut parsed: Vec<(String, Vec<String>)> = Vec::new();
+    for (line_number, line) in contents.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let Some((dept, names)) = line.split_once(':') else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("line {} is missing ':'", line_number + 1),
+            ));
+        };
+        let names = names
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+            .collect();
+        parsed.push((dept.trim().to_string(), names));
+    }
+
+    for (dept, names) in parsed {
+        let dept = department_key(company, &dept);
+        let employees = company.entry(dept).or_default();
+        for name in names {
+            if !employees.iter().any(|e| e.eq_ignore_ascii_case(&name)) {
+                employees.push(name);
             }
-        } else {
-            println!("❌ Unknown command.");
         }
     }
+    Ok(())
+}
+
+
+// 📤 CSV with a `department,name` header and one row per employee, both sorted
+pub fn to_csv(company: &HashMap<String, Vec<String>>) -> String {
+    let mut departments: Vec<&String> = company.keys().collect();
+    departments.sort();
+
+    let mut csv = String::from("department,name\n");
+    for dept in departments {
+        let mut names = company[dept].clone();
+        names.sort();
+        for name in names {
+            csv.push_str(&format!("{},{}\n", csv_field(dept), csv_field(&name)));
+        }
+    }
+    csv
+}
+
+
+// RFC 4180: fields with commas, quotes or line breaks are wrapped in quotes,
+// and any quote inside the field is doubled
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!("task1_{}_{}.txt", name, std::process::id()))
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[test]
+    fn add_rejects_commas_and_colons() {
+        let mut company = HashMap::new();
+        for cmd in [
+            parse_command("Add \"Smith, John\" to Sales"),
+            parse_command("Add Amir to \"R&D: Labs\""),
+        ] {
+            assert_eq!(apply(&mut company, &cmd), "❌ Names and departments can't contain ',' or ':'.");
+        }
+        assert!(company.is_empty());
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let mut company = HashMap::new();
+        for line in [
+            "Add \"John Smith\" to \"Sales Team\"",
+            "Add Amir to Sales",
+            "Add Sally to Engineering",
+        ] {
+            apply(&mut company, &parse_command(line));
+        }
+
+        let path = temp_path("round_trip");
+        save_directory(&company, &path).unwrap();
+        let mut loaded = HashMap::new();
+        load_directory(&mut loaded, &path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, company);
+    }
+
+    #[test]
+    fn parses_add_and_remove() {
+        assert_eq!(
+            parse_command("Add Sally to Engineering"),
+            Command::Add { name: "Sally".to_string(), dept: "Engineering".to_string() }
+        );
+        assert_eq!(
+            parse_command("  add \"John Smith\" TO \"Sales Team\"  "),
+            Command::Add { name: "John Smith".to_string(), dept: "Sales Team".to_string() }
+        );
+        assert_eq!(
+            parse_command("Remove Sally from Engineering"),
+            Command::Remove { name: "Sally".to_string(), dept: "Engineering".to_string() }
+        );
+    }
+
+    #[test]
+    fn parses_show_save_load_export_and_exit() {
+        assert_eq!(parse_command("Show Sales"), Command::Show("Sales".to_string()));
+        assert_eq!(parse_command("show all"), Command::ShowAll);
+        assert_eq!(parse_command("Save dir.txt"), Command::Save("dir.txt".to_string()));
+        assert_eq!(parse_command("Load dir.txt"), Command::Load("dir.txt".to_string()));
+        assert_eq!(parse_command("Export dir.csv"), Command::Export("dir.csv".to_string()));
+        assert_eq!(parse_command("Exit"), Command::Exit);
+    }
+
+    #[test]
+    fn malformed_commands_are_invalid_or_unknown() {
+        assert_eq!(parse_command("Add Sally Engineering"), Command::Invalid("Add <Name> to <Department>"));
+        assert_eq!(parse_command("Add Sally to"), Command::Invalid("Add <Name> to <Department>"));
+        assert_eq!(parse_command("Remove Sally to Sales"), Command::Invalid("Remove <Name> from <Department>"));
+        assert_eq!(parse_command("Show"), Command::Invalid("Show <Department> or Show All"));
+        assert_eq!(parse_command("Save"), Command::Invalid("Save <Path>"));
+        assert_eq!(parse_command("Load"), Command::Invalid("Load <Path>"));
+        assert_eq!(parse_command("Export"), Command::Invalid("Export <Path>"));
+        assert_eq!(parse_command("Exit now"), Command::Unknown);
+        assert_eq!(parse_command("Fire Sally"), Command::Unknown);
+        assert_eq!(parse_command(""), Command::Unknown);
+    }
+
+    #[test]
+    fn show_finds_department_regardless_of_case() {
+        let mut company = HashMap::new();
+        apply(&mut company, &parse_command("Add Sally to Engineering"));
+        apply(&mut company, &parse_command("Add Amir to engineering"));
+
+        assert_eq!(company.len(), 1);
+        assert_eq!(
+            apply(&mut company, &parse_command("show ENGINEERING")),
+            "\n📂 Department: Engineering\n - Amir\n - Sally"
+        );
+    }
+
+    #[test]
+    fn adding_same_name_twice_keeps_one_entry() {
+        let mut company = HashMap::new();
+        apply(&mut company, &parse_command("Add Sally to Sales"));
+        let message = apply(&mut company, &parse_command("Add sally to Sales"));
+
+        assert_eq!(message, "❌ sally already in Sales");
+        assert_eq!(company["Sales"].len(), 1);
+    }
+
+    #[test]
+    fn same_name_may_join_another_department() {
+        let mut company = HashMap::new();
+        apply(&mut company, &parse_command("Add Sally to Sales"));
+        let message = apply(&mut company, &parse_command("Add Sally to Engineering"));
+
+        assert_eq!(message, "✅ Added Sally to Engineering");
+        assert_eq!(company["Sales"], vec!["Sally"]);
+        assert_eq!(company["Engineering"], vec!["Sally"]);
+    }
+
+    #[test]
+    fn to_csv_writes_header_and_sorted_rows() {
+        let mut company = HashMap::new();
+        company.insert("Sales".to_string(), vec!["Zed".to_string(), "Amir".to_string()]);
+        company.insert("Engineering".to_string(), vec!["Sally".to_string()]);
+
+        assert_eq!(
+            to_csv(&company),
+            "department,name\nEngineering,Sally\nSales,Amir\nSales,Zed\n"
+        );
+        assert_eq!(to_csv(&HashMap::new()), "department,name\n");
+    }
+
+    #[test]
+    fn to_csv_quotes_commas_and_doubles_quotes() {
+        let mut company = HashMap::new();
+        company.insert(
+            "R&D, Labs".to_string(),
+            vec!["Smith, John".to_string(), "Dwayne \"The Rock\"".to_string()],
+        );
+
+        assert_eq!(
+            to_csv(&company),
+            "department,name\n\"R&D, Labs\",\"Dwayne \"\"The Rock\"\"\"\n\"R&D, Labs\",\"Smith, John\"\n"
+        );
+    }
+
+    #[test]
+    fn median_mode_odd_length() {
+        assert_eq!(median_mode(&[3, 1, 2, 2, 5]), Some((2.0, 2)));
+    }
+
+    #[test]
+    fn median_mode_even_length_averages_middle_values() {
+        assert_eq!(median_mode(&[4, 1, 3, 3]), Some((3.0, 3)));
+        assert_eq!(median_mode(&[1, 2, 3, 4]), Some((2.5, 1)));
+    }
+
+    #[test]
+    fn median_mode_clear_single_mode() {
+        let numbers = [1, 2, 3, 4, 5, 6, 1, 2, 2, 3, 5, 2, 2, 2, 2, 3, 5];
+        assert_eq!(median_mode(&numbers), Some((2.0, 2)));
+    }
+
+    #[test]
+    fn median_mode_empty_is_none() {
+        assert_eq!(median_mode::<i32>(&[]), None);
+    }
+
+    #[test]
+    fn median_mode_single_element() {
+        assert_eq!(median_mode(&[7]), Some((7.0, 7)));
+    }
+
+    #[test]
+    fn modes_unimodal() {
+        assert_eq!(modes(&[1, 2, 2, 3]), vec![2]);
+    }
+
+    #[test]
+    fn modes_bimodal() {
+        assert_eq!(modes(&[1, 1, 2, 2, 3]), vec![1, 2]);
+    }
+
+    #[test]
+    fn modes_all_tied_returns_every_value_sorted() {
+        assert_eq!(modes(&[3, 1, 2]), vec![1, 2, 3]);
+        assert!(modes::<i32>(&[]).is_empty());
+    }
+
+    #[test]
+    fn mean_and_variance() {
+        let numbers = [2, 4, 4, 4, 5, 5, 7, 9];
+        assert_eq!(mean(&numbers), Some(5.0));
+        assert_eq!(variance(&numbers), Some(4.0));
+        assert_eq!(mean::<i32>(&[]), None);
+        assert_eq!(variance::<i32>(&[]), None);
+    }
+
+    #[test]
+    fn median_mode_works_for_i64_and_u8() {
+        let wide: [i64; 4] = [-5_000_000_000, 10, 10, 20];
+        assert_eq!(median_mode(&wide), Some((10.0, 10)));
+
+        let bytes: [u8; 5] = [255, 0, 255, 1, 2];
+        assert_eq!(median_mode(&bytes), Some((2.0, 255)));
+    }
+
+    #[test]
+    fn pig_latin_vowel_and_consonant_words() {
+        assert_eq!(pig_latin("apple"), "apple-hay");
+        assert_eq!(pig_latin("banana"), "anana-bay");
+    }
+
+    #[test]
+    fn pig_latin_empty_and_padded_input() {
+        assert_eq!(translate_word(""), "");
+        assert_eq!(pig_latin(""), "");
+        assert_eq!(pig_latin("   "), "");
+        assert_eq!(pig_latin("  first   pig  "), "irst-fay ig-pay");
+    }
+
+    #[test]
+    fn pig_latin_multibyte_first_letter() {
+        // 'ü' is not one of the ASCII vowels, so it moves with the consonants
+        assert_eq!(pig_latin("über"), "er-übay");
+        assert_eq!(pig_latin("café"), "afé-cay");
+    }
+
+    #[test]
+    fn pig_latin_keeps_capital_at_front() {
+        assert_eq!(pig_latin("Hello"), "Ello-hay");
+        assert_eq!(pig_latin("Apple"), "Apple-hay");
+    }
+
+    #[test]
+    fn pig_latin_keeps_trailing_punctuation() {
+        assert_eq!(pig_latin("hello, world"), "ello-hay, orld-way");
+        assert_eq!(pig_latin("this is fun!"), "is-thay is-hay un-fay!");
+        assert_eq!(pig_latin("don't"), "on't-day");
+    }
+
+    #[test]
+    fn pig_latin_moves_whole_consonant_cluster() {
+        assert_eq!(pig_latin("string"), "ing-stray");
+        assert_eq!(pig_latin("glove"), "ove-glay");
+        assert_eq!(pig_latin("rhythm"), "rhythm-ay");
+    }
+
+    #[test]
+    fn pig_latin_vowel_initial_words() {
+        assert_eq!(pig_latin("egg"), "egg-hay");
+        assert_eq!(pig_latin("Umbrella"), "Umbrella-hay");
+    }
+
+    #[test]
+    fn failed_load_leaves_directory_unchanged() {
+        let mut company = HashMap::new();
+        apply(&mut company, &parse_command("Add Sally to Engineering"));
+        let before = company.clone();
+
+        // The first line is valid, so a line-by-line merge would already have added Sales
+        let path = temp_path("malformed");
+        fs::write(&path, "Sales: Amir, John\nno colon here\n").unwrap();
+        let result = load_directory(&mut company, &path);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
+        assert_eq!(company, before);
+    }
+
+    #[test]
+    fn load_keeps_departments_missing_from_the_file() {
+        let mut company = HashMap::new();
+        apply(&mut company, &parse_command("Add Sally to Engineering"));
+        apply(&mut company, &parse_command("Add Amir to Sales"));
+
+        let path = temp_path("no_clobber");
+        fs::write(&path, "sales: John\n").unwrap();
+        load_directory(&mut company, &path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(company.len(), 2);
+        assert_eq!(company["Engineering"], vec!["Sally"]);
+        assert_eq!(company["Sales"], vec!["Amir", "John"]);
+    }
+
+    #[test]
+    fn empty_quoted_arguments_are_invalid() {
+        assert_eq!(parse_command("Add \"\" to Sales"), Command::Invalid("Add <Name> to <Department>"));
+        assert_eq!(parse_command("Add \"   \" to Sales"), Command::Invalid("Add <Name> to <Department>"));
+        assert_eq!(parse_command("Remove Sally from \"\""), Command::Invalid("Remove <Name> from <Department>"));
+        assert_eq!(parse_command("Show \"\""), Command::Invalid("Show <Department> or Show All"));
+    }
+
+    #[test]
+    fn unclosed_quotes_are_invalid() {
+        assert_eq!(parse_command("Add \"John Smith to Sales"), Command::Invalid("Add <Name> to <Department>"));
+        assert_eq!(parse_command("Add Amir to \"Sales"), Command::Invalid("Add <Name> to <Department>"));
+        assert_eq!(parse_command("Show \"Sales Team"), Command::Invalid("Show <Department> or Show All"));
+    }
 }
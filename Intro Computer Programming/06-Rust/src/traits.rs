@@ -2,7 +2,7 @@
 // TRAITS EXAMPLES
 // ===========================
 
-use std::fmt::Display;
+use std::fmt::{self, Display};
 
 // 1. Basic Trait Definition
 pub trait Drawable {
@@ -17,6 +17,17 @@ pub trait Drawable {
     fn area(&self) -> f64 {
         0.0 // Default area
     }
+    
+    fn perimeter(&self) -> f64 {
+        0.0 // Default perimeter
+    }
+    
+    // No default - every shape must say how much space it takes up
+    fn bounding_box(&self) -> (f64, f64);
+    
+    // No default either - the SVG element depends on the shape.
+    // Shapes are placed so their bounding box starts at the origin
+    fn to_svg(&self) -> String;
 }
 
 // 2. Trait with Associated Types
@@ -69,6 +80,15 @@ pub trait Animal {
     fn make_sound(&self) {
         println!("{} says {}", self.name(), self.sound());
     }
+    
+    // Most of our animals walk on four legs; others override this
+    fn number_of_legs(&self) -> u8 {
+        4
+    }
+    
+    fn describe_legs(&self) {
+        println!("{} has {} legs", self.name(), self.number_of_legs());
+    }
 }
 
 pub trait Mammal: Animal {
@@ -79,6 +99,16 @@ pub trait Mammal: Animal {
     }
 }
 
+pub trait Bird: Animal {
+    fn can_fly(&self) -> bool;
+    fn wingspan_cm(&self) -> u32;
+    
+    fn describe_bird(&self) {
+        let flight = if self.can_fly() { "can fly" } else { "cannot fly" };
+        println!("{} is a bird with a {} cm wingspan that {}", self.name(), self.wingspan_cm(), flight);
+    }
+}
+
 // 8. Structs implementing traits
 #[derive(Debug, Clone)]
 pub struct Circle {
@@ -103,6 +133,12 @@ pub struct Cat {
     pub fur_color: String,
 }
 
+#[derive(Debug)]
+pub struct Parrot {
+    pub name: String,
+    pub wingspan_cm: u32,
+}
+
 // Implementing Drawable for shapes
 impl Drawable for Circle {
     fn draw(&self) {
@@ -112,6 +148,19 @@ impl Drawable for Circle {
     fn area(&self) -> f64 {
         std::f64::consts::PI * self.radius * self.radius
     }
+    
+    fn perimeter(&self) -> f64 {
+        2.0 * std::f64::consts::PI * self.radius
+    }
+    
+    fn bounding_box(&self) -> (f64, f64) {
+        let diameter = 2.0 * self.radius;
+        (diameter, diameter)
+    }
+    
+    fn to_svg(&self) -> String {
+        format!(r#"<circle r="{0}" cx="{0}" cy="{0}"/>"#, self.radius)
+    }
 }
 
 impl Drawable for Rectangle {
@@ -122,6 +171,18 @@ impl Drawable for Rectangle {
     fn area(&self) -> f64 {
         self.width * self.height
     }
+    
+    fn perimeter(&self) -> f64 {
+        2.0 * (self.width + self.height)
+    }
+    
+    fn bounding_box(&self) -> (f64, f64) {
+        (self.width, self.height)
+    }
+    
+    fn to_svg(&self) -> String {
+        format!(r#"<rect width="{}" height="{}" x="0" y="0"/>"#, self.width, self.height)
+    }
 }
 
 // Implementing Animal and Mammal for pets
@@ -157,6 +218,31 @@ impl Mammal for Cat {
     }
 }
 
+// Implementing Animal and Bird for Parrot
+impl Animal for Parrot {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    
+    fn sound(&self) -> &str {
+        "Squawk!"
+    }
+    
+    fn number_of_legs(&self) -> u8 {
+        2
+    }
+}
+
+impl Bird for Parrot {
+    fn can_fly(&self) -> bool {
+        true
+    }
+    
+    fn wingspan_cm(&self) -> u32 {
+        self.wingspan_cm
+    }
+}
+
 // Implementing Cloneable
 impl Cloneable for Circle {
     fn clone_self(&self) -> Self {
@@ -173,6 +259,13 @@ impl Cloneable for Rectangle {
     }
 }
 
+// Display lets a Rectangle be passed to print_and_clone
+impl Display for Rectangle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Rectangle {}x{}", self.width, self.height)
+    }
+}
+
 // Implementing marker trait
 impl Printable for Circle {}
 impl Printable for Rectangle {}
@@ -190,11 +283,20 @@ impl<T: Display> Convertible<String> for T {
 pub struct Counter {
     current: u32,
     max: u32,
+    step: u32,
 }
 
 impl Counter {
     pub fn new(max: u32) -> Counter {
-        Counter { current: 0, max }
+        Counter { current: 0, max, step: 1 }
+    }
+    
+    // A step of 0 would never reach max, so it is rejected
+    pub fn with_step(max: u32, step: u32) -> Result<Counter, String> {
+        if step == 0 {
+            return Err(String::from("step must be greater than 0"));
+        }
+        Ok(Counter { current: 0, max, step })
     }
 }
 
@@ -204,7 +306,8 @@ impl MyIterator for Counter {
     fn next(&mut self) -> Option<Self::Item> {
         if self.current < self.max {
             let current = self.current;
-            self.current += 1;
+            // Stop instead of overflowing when the next step would pass u32::MAX
+            self.current = current.checked_add(self.step).unwrap_or(self.max);
             Some(current)
         } else {
             None
@@ -212,11 +315,22 @@ impl MyIterator for Counter {
     }
 }
 
+// The standard library's Iterator gives `for` loops and adapters like map and sum
+// for free. Counter now has two `next` methods, so calls must name the trait
+impl Iterator for Counter {
+    type Item = u32;
+    
+    fn next(&mut self) -> Option<Self::Item> {
+        MyIterator::next(self)
+    }
+}
+
 // 11. Trait bounds in function parameters
 pub fn draw_shape(shape: &dyn Drawable) {
     shape.draw();
     shape.describe();
     println!("Area: {:.2}", shape.area());
+    println!("Perimeter: {:.2}", shape.perimeter());
 }
 
 // 12. Generic function with trait bounds
@@ -237,11 +351,19 @@ where
 
 // 14. Function using trait objects
 pub fn make_animals_sound(animals: &[Box<dyn Animal>]) {
-    for animal in animals {
-        animal.make_sound();
+    for line in collect_animal_sounds(animals) {
+        println!("{}", line);
     }
 }
 
+// Same lines as make_sound, returned instead of printed so they can be checked
+pub fn collect_animal_sounds(animals: &[Box<dyn Animal>]) -> Vec<String> {
+    animals
+        .iter()
+        .map(|animal| format!("{} says {}", animal.name(), animal.sound()))
+        .collect()
+}
+
 // 15. Function with associated types
 pub fn process_iterator<I>(mut iter: I) -> Vec<I::Item>
 where
@@ -255,7 +377,8 @@ pub trait Storage<T>
 where 
     T: Clone,
 {
-    fn store(&mut self, item: T);
+    // Hands the item back in Err if the storage has no room for it
+    fn store(&mut self, item: T) -> Result<(), T>;
     fn retrieve(&self) -> Option<&T>;
     fn remove(&mut self) -> Option<T>;
 }
@@ -272,9 +395,17 @@ impl<T> SimpleStorage<T> {
     }
 }
 
+impl<T> Default for SimpleStorage<T> {
+    fn default() -> Self {
+        SimpleStorage::new()
+    }
+}
+
 impl<T: Clone> Storage<T> for SimpleStorage<T> {
-    fn store(&mut self, item: T) {
+    // A single slot: storing again replaces the old item, so this never fails
+    fn store(&mut self, item: T) -> Result<(), T> {
         self.item = Some(item);
+        Ok(())
     }
     
     fn retrieve(&self) -> Option<&T> {
@@ -286,6 +417,49 @@ impl<T: Clone> Storage<T> for SimpleStorage<T> {
     }
 }
 
+// Storage with room for a fixed number of items, used like a stack
+#[derive(Debug)]
+pub struct BoundedStorage<T> {
+    items: Vec<T>,
+    capacity: usize,
+}
+
+impl<T> BoundedStorage<T> {
+    pub fn new(capacity: usize) -> Self {
+        BoundedStorage {
+            items: Vec::with_capacity(capacity),
+            capacity,
+        }
+    }
+    
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+    
+    pub fn is_full(&self) -> bool {
+        self.items.len() >= self.capacity
+    }
+}
+
+impl<T: Clone> Storage<T> for BoundedStorage<T> {
+    fn store(&mut self, item: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(item);
+        }
+        self.items.push(item);
+        Ok(())
+    }
+    
+    // The most recently stored item
+    fn retrieve(&self) -> Option<&T> {
+        self.items.last()
+    }
+    
+    fn remove(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+}
+
 // 18. Trait for mathematical operations
 pub trait Addable<Rhs = Self> {
     type Output;
@@ -304,6 +478,20 @@ impl Point {
     pub fn new(x: f64, y: f64) -> Self {
         Point { x, y }
     }
+    
+    pub fn scale(&self, factor: f64) -> Point {
+        Point {
+            x: self.x * factor,
+            y: self.y * factor,
+        }
+    }
+    
+    // Euclidean distance: the length of the line between the two points
+    pub fn distance(&self, other: &Point) -> f64 {
+        let dx = self.x - other.x;
+        let dy = self.y - other.y;
+        (dx * dx + dy * dy).sqrt()
+    }
 }
 
 impl Addable for Point {
@@ -317,6 +505,27 @@ impl Addable for Point {
     }
 }
 
+// The standard library's version of Addable - implementing it enables `p1 + p2`.
+// std::ops::Add isn't imported, so `.add(...)` calls above still mean Addable
+impl std::ops::Add for Point {
+    type Output = Point;
+    
+    fn add(self, rhs: Point) -> Self::Output {
+        Addable::add(self, rhs)
+    }
+}
+
+impl std::ops::Sub for Point {
+    type Output = Point;
+    
+    fn sub(self, rhs: Point) -> Self::Output {
+        Point {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
 // 20. Builder pattern trait
 pub trait Builder {
     type Output;
@@ -324,10 +533,30 @@ pub trait Builder {
     fn build(self) -> Self::Output;
 }
 
+// Shared by builders: turns a missing field into a named error
+pub fn require<T>(field: Option<T>, name: &str) -> Result<T, String> {
+    field.ok_or_else(|| format!("{} is required", name))
+}
+
+// Deliberately minimal: exactly one '@', something before it, and a
+// domain after it made of two or more non-empty parts separated by '.'
+fn is_valid_email(email: &str) -> bool {
+    match email.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && !domain.contains('@')
+                && domain.contains('.')
+                && domain.split('.').all(|label| !label.is_empty())
+        }
+        None => false,
+    }
+}
+
 pub struct PersonBuilder {
     name: Option<String>,
     age: Option<u32>,
     email: Option<String>,
+    phone: Option<String>,
 }
 
 #[derive(Debug)]
@@ -335,6 +564,7 @@ pub struct Person {
     pub name: String,
     pub age: u32,
     pub email: String,
+    pub phone: String,
 }
 
 impl PersonBuilder {
@@ -343,6 +573,7 @@ impl PersonBuilder {
             name: None,
             age: None,
             email: None,
+            phone: None,
         }
     }
     
@@ -360,17 +591,82 @@ impl PersonBuilder {
         self.email = Some(email);
         self
     }
+    
+    pub fn phone(mut self, phone: String) -> Self {
+        self.phone = Some(phone);
+        self
+    }
 }
 
 impl Builder for PersonBuilder {
     type Output = Result<Person, String>;
     
     fn build(self) -> Self::Output {
-        let name = self.name.ok_or("Name is required")?;
-        let age = self.age.ok_or("Age is required")?;
-        let email = self.email.ok_or("Email is required")?;
+        let name = require(self.name, "Name")?;
+        let age = require(self.age, "Age")?;
+        let email = require(self.email, "Email")?;
         
-        Ok(Person { name, age, email })
+        if !is_valid_email(&email) {
+            return Err("invalid email".into());
+        }
+        
+        // Optional: an unset phone becomes an empty string
+        let phone = self.phone.unwrap_or_default();
+        
+        Ok(Person { name, age, email, phone })
+    }
+}
+
+// 21. Enum dispatch - static alternative to Box<dyn Drawable>
+#[derive(Debug, Clone)]
+pub enum Shape {
+    Circle(Circle),
+    Rectangle(Rectangle),
+}
+
+// Implementing Drawable by delegating lets a Vec<Shape> mix circles and
+// rectangles and still be passed to generic functions like draw_multiple_shapes
+impl Drawable for Shape {
+    fn draw(&self) {
+        match self {
+            Shape::Circle(circle) => circle.draw(),
+            Shape::Rectangle(rectangle) => rectangle.draw(),
+        }
+    }
+
+    fn describe(&self) {
+        match self {
+            Shape::Circle(circle) => circle.describe(),
+            Shape::Rectangle(rectangle) => rectangle.describe(),
+        }
+    }
+
+    fn area(&self) -> f64 {
+        match self {
+            Shape::Circle(circle) => circle.area(),
+            Shape::Rectangle(rectangle) => rectangle.area(),
+        }
+    }
+
+    fn perimeter(&self) -> f64 {
+        match self {
+            Shape::Circle(circle) => circle.perimeter(),
+            Shape::Rectangle(rectangle) => rectangle.perimeter(),
+        }
+    }
+
+    fn bounding_box(&self) -> (f64, f64) {
+        match self {
+            Shape::Circle(circle) => circle.bounding_box(),
+            Shape::Rectangle(rectangle) => rectangle.bounding_box(),
+        }
+    }
+
+    fn to_svg(&self) -> String {
+        match self {
+            Shape::Circle(circle) => circle.to_svg(),
+            Shape::Rectangle(rectangle) => rectangle.to_svg(),
+        }
     }
 }
 
@@ -378,6 +674,7 @@ impl Builder for PersonBuilder {
 // MAIN FUNCTION WITH EXAMPLES
 // ===========================
 
+#[allow(clippy::approx_constant)]
 pub fn run_traits_examples() {
     println!("=== TRAITS EXAMPLES ===\n");
     
@@ -415,6 +712,17 @@ pub fn run_traits_examples() {
     dog.describe_mammal();
     cat.describe_mammal();
     
+    dog.describe_legs();
+    cat.describe_legs();
+    
+    let parrot = Parrot {
+        name: "Polly".to_string(),
+        wingspan_cm: 50,
+    };
+    parrot.make_sound();
+    parrot.describe_legs();
+    parrot.describe_bird();
+    
     println!();
     
     // Trait objects
@@ -442,7 +750,7 @@ pub fn run_traits_examples() {
     // Iterator trait
     let mut counter = Counter::new(5);
     println!("Counter values:");
-    while let Some(value) = counter.next() {
+    while let Some(value) = MyIterator::next(&mut counter) {
         println!("  {}", value);
     }
     
@@ -451,6 +759,16 @@ pub fn run_traits_examples() {
     let all_values = process_iterator(counter2);
     println!("All counter values: {:?}", all_values);
     
+    // Same counter through std::iter::Iterator
+    let total: u32 = Counter::new(5).sum();
+    let squares: Vec<u32> = Counter::new(4).map(|n| n * n).collect();
+    println!("Sum of counter: {}, squares: {:?}", total, squares);
+    
+    match Counter::with_step(10, 3) {
+        Ok(stepped) => println!("Counting by 3: {:?}", stepped.collect::<Vec<_>>()),
+        Err(e) => println!("Error: {}", e),
+    }
+    
     println!();
     
     // Convertible trait
@@ -472,7 +790,7 @@ pub fn run_traits_examples() {
     // Storage trait
     let mut storage = SimpleStorage::new();
     
-    storage.store("Hello, World!".to_string());
+    let _ = storage.store("Hello, World!".to_string());
     if let Some(item) = storage.retrieve() {
         println!("Retrieved from storage: {}", item);
     }
@@ -484,16 +802,33 @@ pub fn run_traits_examples() {
         println!("Storage is now empty");
     }
     
+    // Bounded storage rejects items once it is full
+    let mut bounded = BoundedStorage::new(2);
+    for word in ["one", "two", "three"] {
+        match bounded.store(word) {
+            Ok(()) => println!("Stored {}", word),
+            Err(rejected) => println!("Storage full, rejected {}", rejected),
+        }
+    }
+    println!("Removed from bounded storage: {:?}", bounded.remove());
+    
+    let default_storage: SimpleStorage<i32> = SimpleStorage::default();
+    println!("Default storage holds: {:?}", default_storage.retrieve());
+    
     println!();
     
     // Mathematical operations
     let point1 = Point::new(1.0, 2.0);
     let point2 = Point::new(3.0, 4.0);
-    let sum = point1.clone().add(point2);
+    let sum = point1.clone().add(point2.clone());
     
     println!("Point 1: {:?}", point1);
     println!("Point 2: {:?}", point2);
     println!("Sum: {:?}", sum);
+    println!("Sum with +: {:?}", Point::new(1.0, 2.0) + Point::new(3.0, 4.0));
+    println!("Difference with -: {:?}", Point::new(3.0, 4.0) - Point::new(1.0, 2.0));
+    println!("Scaled by 2: {:?}", Point::new(1.0, 2.0).scale(2.0));
+    println!("Distance from origin to (3, 4): {}", Point::new(0.0, 0.0).distance(&Point::new(3.0, 4.0)));
     
     println!();
     
@@ -502,6 +837,7 @@ pub fn run_traits_examples() {
         .name("Alice Johnson".to_string())
         .age(30)
         .email("alice@example.com".to_string())
+        .phone("555-0100".to_string())
         .build();
     
     match person_result {
@@ -520,4 +856,221 @@ pub fn run_traits_examples() {
         Ok(person) => println!("Built person: {:?}", person),
         Err(e) => println!("Failed to build person: {}", e),
     }
-}
\ No newline at end of file
+    
+    // Builder with a malformed email
+    let invalid_email_result = PersonBuilder::new()
+        .name("Carol".to_string())
+        .age(41)
+        .email("carol.example.com".to_string())
+        .build();
+    
+    match invalid_email_result {
+        Ok(person) => println!("Built person: {:?}", person),
+        Err(e) => println!("Failed to build person: {}", e),
+    }
+
+    println!();
+
+    // Enum dispatch - no heap allocation needed
+    let enum_shapes = vec![
+        Shape::Circle(Circle { radius: 1.0 }),
+        Shape::Rectangle(Rectangle { width: 2.0, height: 3.0 }),
+    ];
+    for shape in &enum_shapes {
+        shape.draw();
+        println!("Area: {:.2}", shape.area());
+        println!("Perimeter: {:.2}", shape.perimeter());
+        println!("Bounding box: {:?}", shape.bounding_box());
+        println!("SVG: {}", shape.to_svg());
+    }
+
+    // A mixed Vec<Shape> works with the generic Drawable functions
+    draw_multiple_shapes(&enum_shapes);
+    let total_area: f64 = enum_shapes.iter().map(|shape| shape.area()).sum();
+    println!("Total area of mixed shapes: {:.2}", total_area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shape_area_matches_trait_objects() {
+        let circle = Circle { radius: 2.0 };
+        let rectangle = Rectangle { width: 3.0, height: 4.0 };
+        let boxed: Vec<Box<dyn Drawable>> = vec![Box::new(circle.clone()), Box::new(rectangle.clone())];
+        let shapes = [Shape::Circle(circle), Shape::Rectangle(rectangle)];
+
+        for (shape, object) in shapes.iter().zip(&boxed) {
+            assert_eq!(shape.area(), object.area());
+        }
+    }
+
+    // Only needs T: Drawable, so it exercises the impl Drawable for Shape
+    fn total_area<T: Drawable>(shapes: &[T]) -> f64 {
+        shapes.iter().map(|shape| shape.area()).sum()
+    }
+
+    #[test]
+    fn mixed_shapes_work_with_generic_functions() {
+        let mut shapes: Vec<Shape> = vec![Shape::Circle(Circle { radius: 1.0 })];
+        shapes.push(Shape::Rectangle(Rectangle { width: 2.0, height: 3.0 }));
+
+        draw_multiple_shapes(&shapes);
+        assert!((total_area(&shapes) - (std::f64::consts::PI + 6.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn counter_works_with_std_iterator() {
+        let total: u32 = Counter::new(5).sum();
+        assert_eq!(total, 10);
+        let squares: Vec<u32> = Counter::new(4).map(|n| n * n).collect();
+        assert_eq!(squares, vec![0, 1, 4, 9]);
+        assert_eq!(Counter::new(3).collect_all(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn counter_with_step() {
+        let evens: Vec<u32> = Counter::with_step(10, 2).unwrap().collect();
+        assert_eq!(evens, vec![0, 2, 4, 6, 8]);
+        assert_eq!(Counter::with_step(10, 0).err(), Some("step must be greater than 0".to_string()));
+    }
+
+    #[test]
+    fn counter_with_step_stops_before_overflow() {
+        let values: Vec<u32> = Counter::with_step(u32::MAX, u32::MAX - 1).unwrap().collect();
+        assert_eq!(values, vec![0, u32::MAX - 1]);
+    }
+
+    #[test]
+    fn perimeters() {
+        let circle = Circle { radius: 1.0 };
+        assert!((circle.perimeter() - 2.0 * std::f64::consts::PI).abs() < 1e-9);
+        assert_eq!(Rectangle { width: 2.0, height: 3.0 }.perimeter(), 10.0);
+    }
+
+    #[test]
+    fn bounding_boxes() {
+        assert_eq!(Circle { radius: 2.0 }.bounding_box(), (4.0, 4.0));
+        assert_eq!(Rectangle { width: 3.0, height: 4.0 }.bounding_box(), (3.0, 4.0));
+    }
+
+    #[test]
+    fn svg_output() {
+        assert_eq!(Circle { radius: 5.0 }.to_svg(), r#"<circle r="5" cx="5" cy="5"/>"#);
+        assert_eq!(
+            Rectangle { width: 3.0, height: 4.0 }.to_svg(),
+            r#"<rect width="3" height="4" x="0" y="0"/>"#
+        );
+    }
+
+    #[test]
+    fn point_addition() {
+        let expected = Point::new(4.0, 6.0);
+        assert_eq!(Point::new(1.0, 2.0) + Point::new(3.0, 4.0), expected);
+        assert_eq!(Point::new(1.0, 2.0).add(Point::new(3.0, 4.0)), expected);
+    }
+
+    #[test]
+    fn point_sub_scale_distance() {
+        assert_eq!(Point::new(5.0, 7.0) - Point::new(2.0, 3.0), Point::new(3.0, 4.0));
+        assert_eq!(Point::new(1.5, -2.0).scale(2.0), Point::new(3.0, -4.0));
+        assert_eq!(Point::new(0.0, 0.0).distance(&Point::new(3.0, 4.0)), 5.0);
+    }
+
+    #[test]
+    fn number_of_legs() {
+        let dog = Dog { name: "Rex".into(), fur_color: "brown".into() };
+        let parrot = Parrot { name: "Polly".into(), wingspan_cm: 50 };
+        assert_eq!(dog.number_of_legs(), 4);
+        assert_eq!(parrot.number_of_legs(), 2);
+    }
+
+    // Only a Bird bound: Animal's methods come along through the supertrait
+    fn bird_summary<B: Bird>(bird: &B) -> String {
+        bird.make_sound();
+        format!("{} says {} and flies: {}", bird.name(), bird.sound(), bird.can_fly())
+    }
+
+    #[test]
+    fn parrot_is_a_bird() {
+        let parrot = Parrot { name: "Polly".into(), wingspan_cm: 50 };
+        assert!(parrot.can_fly());
+        assert_eq!(parrot.wingspan_cm(), 50);
+        assert_eq!(bird_summary(&parrot), "Polly says Squawk! and flies: true");
+    }
+
+    #[test]
+    fn animal_sounds() {
+        let animals: Vec<Box<dyn Animal>> = vec![
+            Box::new(Dog { name: "Rex".into(), fur_color: "brown".into() }),
+            Box::new(Cat { name: "Tom".into(), fur_color: "grey".into() }),
+        ];
+        assert_eq!(collect_animal_sounds(&animals), vec!["Rex says Woof!", "Tom says Meow!"]);
+        assert!(collect_animal_sounds(&[]).is_empty());
+    }
+
+    #[test]
+    fn bounded_storage_fills_and_pops_lifo() {
+        let mut storage = BoundedStorage::new(2);
+        assert_eq!(storage.store(1), Ok(()));
+        assert_eq!(storage.store(2), Ok(()));
+        assert!(storage.is_full());
+        // The rejected item is handed back
+        assert_eq!(storage.store(3), Err(3));
+        assert_eq!(storage.len(), 2);
+        assert_eq!(storage.retrieve(), Some(&2));
+        assert_eq!(storage.remove(), Some(2));
+        assert_eq!(storage.remove(), Some(1));
+        assert_eq!(storage.remove(), None);
+    }
+
+    #[test]
+    fn zero_capacity_storage_rejects_everything() {
+        let mut storage = BoundedStorage::new(0);
+        assert_eq!(storage.store("x"), Err("x"));
+    }
+
+    #[test]
+    fn simple_storage_default_is_empty() {
+        assert_eq!(SimpleStorage::<i32>::default().retrieve(), None);
+        let mut storage = SimpleStorage::default();
+        assert_eq!(storage.store(7), Ok(()));
+        assert_eq!(storage.retrieve(), Some(&7));
+    }
+
+    #[test]
+    fn require_field() {
+        assert_eq!(require(Some(3), "Age"), Ok(3));
+        assert_eq!(require::<u32>(None, "Age"), Err("Age is required".to_string()));
+        let missing_name = PersonBuilder::new().age(30).email("a@b.c".into()).build();
+        assert_eq!(missing_name.err(), Some("Name is required".to_string()));
+    }
+
+    fn build_with_email(email: &str) -> Result<Person, String> {
+        PersonBuilder::new().name("Ann".into()).age(30).email(email.into()).build()
+    }
+
+    #[test]
+    fn email_validation() {
+        assert!(build_with_email("ann@example.com").is_ok());
+        for email in ["ann.example.com", "@example.com", "ann@", "ann@@example.com", "ann@example", "a@.", "a@b.", "a@.com", "a@b..com"] {
+            assert_eq!(build_with_email(email).err(), Some("invalid email".to_string()), "{}", email);
+        }
+    }
+
+    #[test]
+    fn optional_phone() {
+        let without = build_with_email("ann@example.com").unwrap();
+        assert_eq!(without.phone, "");
+        let with = PersonBuilder::new()
+            .name("Ann".into())
+            .age(30)
+            .email("ann@example.com".into())
+            .phone("555-0100".into())
+            .build()
+            .unwrap();
+        assert_eq!(with.phone, "555-0100");
+        assert_eq!(with.name, "Ann");
+    }
+}